@@ -0,0 +1,57 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Embeds the exercises, their solutions and info.toml as they are shipped, so that
+// `rustlings init` and `rustlings reset` work without a copy of the repository
+fn main() {
+    println!("cargo:rerun-if-changed=exercises");
+    println!("cargo:rerun-if-changed=solutions");
+    println!("cargo:rerun-if-changed=info.toml");
+
+    let mut files = vec![PathBuf::from("info.toml")];
+    collect_files(Path::new("exercises"), &mut files).expect("Failed to read the exercises");
+    collect_files(Path::new("solutions"), &mut files).expect("Failed to read the solutions");
+    files.sort();
+
+    let root = env::current_dir().expect("Failed to get the current directory");
+    let mut source = String::from("pub static FILES: &[(&str, &[u8])] = &[\n");
+    for file in files {
+        let key: Vec<String> = file
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        let _ = writeln!(
+            source,
+            "    ({:?}, include_bytes!({:?})),",
+            key.join("/"),
+            root.join(&file)
+        );
+    }
+    source.push_str("];\n");
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("pristine.rs");
+    fs::write(out, source).expect("Failed to write the embedded exercises");
+}
+
+// Collect the files of the exercises, skipping hidden files and build output.
+// Build scripts can't use the crate they build, so this mirrors
+// `exercise::crate_files`, which is what rustlings itself goes by.
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if name == "target" || name == "Cargo.lock" || name.to_string_lossy().starts_with('.') {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            println!("cargo:rerun-if-changed={}", entry.path().display());
+            collect_files(&entry.path(), files)?;
+        } else {
+            files.push(entry.path());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use std::f32;
+
+fn main() {
+    // Clippy catches approximations of constants like 3.14f32,
+    // the standard library has the precise ones
+    let pi = f32::consts::PI;
+    let radius = 5.00f32;
+
+    let area = pi * f32::powi(radius, 2);
+
+    println!(
+        "The area of a circle with radius {:.2} is {:.5}!",
+        radius, area
+    )
+}
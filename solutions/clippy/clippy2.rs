@@ -0,0 +1,9 @@
+fn main() {
+    let mut res = 42;
+    let option = Some(12);
+    // `for x in option` works too, but reads like a loop, which Clippy warns about
+    if let Some(x) = option {
+        res += x;
+    }
+    println!("{res}");
+}
@@ -0,0 +1,21 @@
+fn main() {
+    let my_option: Option<()> = None;
+    // Unwrapping an option known to be `None` would always panic
+    if my_option.is_none() {
+        println!("There is nothing in my option");
+    }
+
+    let my_arr = &[-1, -2, -3, -4, -5, -6];
+    println!("My array! Here it is: {my_arr:?}");
+
+    // `resize` returns `()`, clearing is what empties a vector
+    let mut my_empty_vec = vec![1, 2, 3, 4, 5];
+    my_empty_vec.clear();
+    println!("This Vec is empty, see? {my_empty_vec:?}");
+
+    let mut value_a = 45;
+    let mut value_b = 66;
+    // Assigning one to the other first would lose its value
+    std::mem::swap(&mut value_a, &mut value_b);
+    println!("value a: {value_a}; value b: {value_b}");
+}
@@ -0,0 +1,52 @@
+fn byte_counter<T: AsRef<str>>(arg: T) -> usize {
+    // `as_ref` borrows a `&str` from anything that can give one, like `String`
+    arg.as_ref().len()
+}
+
+// Obtain the number of characters (not bytes) in the given argument.
+fn char_counter<T: AsRef<str>>(arg: T) -> usize {
+    arg.as_ref().chars().count()
+}
+
+// Squares a number using as_mut().
+fn num_sq<T: AsMut<u32>>(arg: &mut T) {
+    // `as_mut` borrows the `u32` in a `Box<u32>` mutably
+    let num = arg.as_mut();
+    *num *= *num;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn different_counts() {
+        let s = "Café au lait";
+        assert_ne!(char_counter(s), byte_counter(s));
+    }
+
+    #[test]
+    fn same_counts() {
+        let s = "Cafe au lait";
+        assert_eq!(char_counter(s), byte_counter(s));
+    }
+
+    #[test]
+    fn different_counts_using_string() {
+        let s = String::from("Café au lait");
+        assert_ne!(char_counter(s.clone()), byte_counter(s));
+    }
+
+    #[test]
+    fn same_counts_using_string() {
+        let s = String::from("Cafe au lait");
+        assert_eq!(char_counter(s.clone()), byte_counter(s));
+    }
+
+    #[test]
+    fn mult_box() {
+        let mut num: Box<u32> = Box::new(3);
+        num_sq(&mut num);
+        assert_eq!(*num, 9);
+    }
+}
@@ -0,0 +1,20 @@
+fn average(values: &[f64]) -> f64 {
+    let total: f64 = values.iter().sum();
+    // `len` is a `usize`, which has to be cast to divide a `f64` by it
+    total / values.len() as f64
+}
+
+fn main() {
+    let values = [3.5, 0.3, 13.0, 11.7];
+    println!("{}", average(&values));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_proper_type_and_value() {
+        assert_eq!(average(&[3.5, 0.3, 13.0, 11.7]), 7.125);
+    }
+}
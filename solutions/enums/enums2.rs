@@ -0,0 +1,28 @@
+// Each variant can hold different data: none, like a unit struct,
+// named fields, like a struct, or unnamed ones, like a tuple struct
+#[derive(Debug)]
+enum Message {
+    Quit,
+    Echo(String),
+    Move { x: i64, y: i64 },
+    ChangeColor(u8, u8, u8),
+}
+
+impl Message {
+    fn call(&self) {
+        println!("{:?}", self);
+    }
+}
+
+fn main() {
+    let messages = [
+        Message::Move { x: 10, y: 30 },
+        Message::Echo(String::from("hello world")),
+        Message::ChangeColor(200, 255, 255),
+        Message::Quit,
+    ];
+
+    for message in &messages {
+        message.call();
+    }
+}
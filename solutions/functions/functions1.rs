@@ -0,0 +1,9 @@
+// The function `main` calls has to be defined somewhere,
+// it doesn't matter whether that's before or after `main`
+fn call_me() {
+    println!("Hello world!");
+}
+
+fn main() {
+    call_me();
+}
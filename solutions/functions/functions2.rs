@@ -0,0 +1,10 @@
+// The types of function parameters are never inferred, they must be annotated
+fn call_me(num: u8) {
+    for i in 0..num {
+        println!("Ring! Call number {}", i + 1);
+    }
+}
+
+fn main() {
+    call_me(3);
+}
@@ -0,0 +1,10 @@
+fn call_me(num: u8) {
+    for i in 0..num {
+        println!("Ring! Call number {}", i + 1);
+    }
+}
+
+fn main() {
+    // Every parameter needs an argument, there are no default values
+    call_me(5);
+}
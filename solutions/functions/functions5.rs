@@ -0,0 +1,10 @@
+fn square(num: i32) -> i32 {
+    // The last expression of the function body is what it returns.
+    // With a semicolon at the end, it would be a statement returning `()`.
+    num * num
+}
+
+fn main() {
+    let answer = square(3);
+    println!("The square of 3 is {answer}");
+}
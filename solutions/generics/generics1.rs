@@ -0,0 +1,6 @@
+fn main() {
+    // `Vec` is generic over the type of its elements. Here it's `&str`,
+    // which could also be left to inference with `Vec<_>`.
+    let mut shopping_list: Vec<&str> = Vec::new();
+    shopping_list.push("milk");
+}
@@ -0,0 +1,25 @@
+pub fn bigger(a: i32, b: i32) -> i32 {
+    // `if` is an expression: whichever branch runs gives the value of the
+    // whole `if`, which is returned as the last expression of the function
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+// Don't mind this for now :)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ten_is_bigger_than_eight() {
+        assert_eq!(10, bigger(10, 8));
+    }
+
+    #[test]
+    fn fortytwo_is_bigger_than_thirtytwo() {
+        assert_eq!(42, bigger(32, 42));
+    }
+}
@@ -0,0 +1,4 @@
+fn main() {
+    // `{}` is a placeholder, filled in with the arguments after the format string
+    println!("Hello {}!", "world");
+}
@@ -0,0 +1,14 @@
+fn main() {
+    let my_fav_fruits = vec!["banana", "custard apple", "avocado", "peach", "raspberry"];
+
+    // `iter` borrows the elements, so `next` gives references to them,
+    // and `None` once they have all been seen
+    let mut my_iterable_fav_fruits = my_fav_fruits.iter();
+
+    assert_eq!(my_iterable_fav_fruits.next(), Some(&"banana"));
+    assert_eq!(my_iterable_fav_fruits.next(), Some(&my_fav_fruits[1]));
+    assert_eq!(my_iterable_fav_fruits.next(), Some(&"avocado"));
+    assert_eq!(my_iterable_fav_fruits.next(), Some(&my_fav_fruits[3]));
+    assert_eq!(my_iterable_fav_fruits.next(), Some(&"raspberry"));
+    assert_eq!(my_iterable_fav_fruits.next(), None);
+}
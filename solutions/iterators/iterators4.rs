@@ -0,0 +1,29 @@
+pub fn factorial(num: u64) -> u64 {
+    // The product of an empty range is 1, which covers the factorial of 0.
+    // `(1..=num).fold(1, |acc, x| acc * x)` is the same, spelled out.
+    (1..=num).product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn factorial_of_0() {
+        assert_eq!(1, factorial(0));
+    }
+
+    #[test]
+    fn factorial_of_1() {
+        assert_eq!(1, factorial(1));
+    }
+    #[test]
+    fn factorial_of_2() {
+        assert_eq!(2, factorial(2));
+    }
+
+    #[test]
+    fn factorial_of_4() {
+        assert_eq!(24, factorial(4));
+    }
+}
@@ -0,0 +1,17 @@
+// The returned reference is one of the two arguments, so it may only live
+// as long as both of them do. The lifetime `'a` tells the compiler so.
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+fn main() {
+    let string1 = String::from("abcd");
+    let string2 = "xyz";
+
+    let result = longest(string1.as_str(), string2);
+    println!("The longest string is '{result}'");
+}
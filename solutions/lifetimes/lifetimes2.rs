@@ -0,0 +1,18 @@
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+fn main() {
+    let string1 = String::from("long string is long");
+    {
+        let string2 = String::from("xyz");
+        let result = longest(string1.as_str(), string2.as_str());
+        // The result may be `string2`, so it can only be used
+        // within the scope `string2` lives in
+        println!("The longest string is '{result}'");
+    }
+}
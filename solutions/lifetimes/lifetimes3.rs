@@ -0,0 +1,17 @@
+// A struct holding references can't outlive what they borrow,
+// which its lifetime parameter expresses
+struct Book<'a> {
+    author: &'a str,
+    title: &'a str,
+}
+
+fn main() {
+    let name = String::from("Jill Smith");
+    let title = String::from("Fish Flying");
+    let book = Book {
+        author: &name,
+        title: &title,
+    };
+
+    println!("{} by {}", book.title, book.author);
+}
@@ -0,0 +1,10 @@
+macro_rules! my_macro {
+    () => {
+        println!("Check out my macro!");
+    };
+}
+
+fn main() {
+    // Macros are called with a `!` after their name
+    my_macro!();
+}
@@ -0,0 +1,10 @@
+// Unlike functions, macros have to be defined before they are used
+macro_rules! my_macro {
+    () => {
+        println!("Check out my macro!");
+    };
+}
+
+fn main() {
+    my_macro!();
+}
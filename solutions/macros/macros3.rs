@@ -0,0 +1,13 @@
+// `#[macro_use]` makes the macros of the module usable after it
+#[macro_use]
+mod macros {
+    macro_rules! my_macro {
+        () => {
+            println!("Check out my macro!");
+        };
+    }
+}
+
+fn main() {
+    my_macro!();
+}
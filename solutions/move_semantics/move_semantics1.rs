@@ -0,0 +1,23 @@
+fn main() {
+    let vec0 = Vec::new();
+
+    // `vec1` is pushed to below, so it has to be declared mutable.
+    // `vec0` was moved into `fill_vec` and can't be used anymore.
+    let mut vec1 = fill_vec(vec0);
+
+    println!("{} has length {} content `{:?}`", "vec1", vec1.len(), vec1);
+
+    vec1.push(88);
+
+    println!("{} has length {} content `{:?}`", "vec1", vec1.len(), vec1);
+}
+
+fn fill_vec(vec: Vec<i32>) -> Vec<i32> {
+    let mut vec = vec;
+
+    vec.push(22);
+    vec.push(44);
+    vec.push(66);
+
+    vec
+}
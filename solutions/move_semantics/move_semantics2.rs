@@ -0,0 +1,28 @@
+// Expected output:
+// vec0 has length 0 content `[]`
+// vec1 has length 4 content `[22, 44, 66, 88]`
+
+fn main() {
+    let vec0 = Vec::new();
+
+    // `fill_vec` only borrows `vec0`, so it can still be used afterwards.
+    // Calling `fill_vec(vec0.clone())` would have worked too.
+    let mut vec1 = fill_vec(&vec0);
+
+    println!("{} has length {} content `{:?}`", "vec0", vec0.len(), vec0);
+
+    vec1.push(88);
+
+    println!("{} has length {} content `{:?}`", "vec1", vec1.len(), vec1);
+}
+
+fn fill_vec(vec: &[i32]) -> Vec<i32> {
+    // The function fills a copy, the borrowed vector stays as it is
+    let mut vec = vec.to_vec();
+
+    vec.push(22);
+    vec.push(44);
+    vec.push(66);
+
+    vec
+}
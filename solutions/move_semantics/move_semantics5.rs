@@ -0,0 +1,10 @@
+fn main() {
+    let mut x = 100;
+    // There can only be one mutable reference to `x` at a time.
+    // `y` isn't used anymore once `z` is created, so its borrow has ended.
+    let y = &mut x;
+    *y += 100;
+    let z = &mut x;
+    *z += 1000;
+    assert_eq!(x, 1200);
+}
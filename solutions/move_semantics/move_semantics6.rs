@@ -0,0 +1,19 @@
+fn main() {
+    let data = "Rust is great!".to_string();
+
+    get_char(&data);
+
+    string_uppercase(data);
+}
+
+// Only borrows the string, which the caller keeps
+fn get_char(data: &String) -> char {
+    data.chars().last().unwrap()
+}
+
+// Takes ownership of the string, which the caller can't use anymore
+fn string_uppercase(mut data: String) {
+    data = data.to_uppercase();
+
+    println!("{data}");
+}
@@ -0,0 +1,16 @@
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    let y: Option<Point> = Some(Point { x: 100, y: 200 });
+
+    // Matching on `&y` only borrows the point, instead of moving it out of `y`.
+    // Writing the pattern as `Some(ref p)` does the same.
+    match &y {
+        Some(p) => println!("Co-ordinates are {},{}", p.x, p.y),
+        _ => panic!("no match!"),
+    }
+    let _ = y;
+}
@@ -0,0 +1,14 @@
+fn main() {
+    // Booleans (`bool`)
+
+    let is_morning = true;
+    if is_morning {
+        println!("Good morning!");
+    }
+
+    // `bool` has exactly two values, `true` and `false`
+    let is_evening = !is_morning;
+    if is_evening {
+        println!("Good evening!");
+    }
+}
@@ -0,0 +1,11 @@
+fn main() {
+    // `[value; length]` creates an array with the value repeated.
+    // Arrays have a fixed length, which is part of their type: `[&str; 100]`.
+    let a = ["Are we there yet?"; 100];
+
+    if a.len() >= 100 {
+        println!("Wow, that's a big array!");
+    } else {
+        println!("Meh, I eat arrays like that for breakfast.");
+    }
+}
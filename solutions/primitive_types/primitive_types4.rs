@@ -0,0 +1,10 @@
+#[test]
+fn slice_out_of_array() {
+    let a = [1, 2, 3, 4, 5];
+
+    // The range starts at index 1 and ends before index 4.
+    // `&a[1..=3]` is the same slice with an inclusive end.
+    let nice_slice = &a[1..4];
+
+    assert_eq!([2, 3, 4], nice_slice);
+}
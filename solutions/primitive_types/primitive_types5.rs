@@ -0,0 +1,8 @@
+fn main() {
+    let cat = ("Furry McFurson", 3.5);
+
+    // A pattern with the shape of the tuple binds each of its elements
+    let (name, age) = cat;
+
+    println!("{name} is {age} years old.");
+}
@@ -0,0 +1,9 @@
+#[test]
+fn indexing_tuple() {
+    let numbers = (1, 2, 3);
+
+    // The elements of a tuple are accessed by their index, starting at 0
+    let second = numbers.1;
+
+    assert_eq!(2, second, "This is not the 2nd number in the tuple!");
+}
@@ -0,0 +1,25 @@
+// Sums every eighth number of 0 to 99 in 8 threads, each with its own offset
+
+#![forbid(unused_imports)]
+use std::sync::Arc;
+use std::thread;
+
+fn main() {
+    let numbers: Vec<_> = (0..100u32).collect();
+    // `Arc` shares the vector between threads without copying it,
+    // counting its owners atomically so that it's safe across threads
+    let shared_numbers = Arc::new(numbers);
+    let mut joinhandles = Vec::new();
+
+    for offset in 0..8 {
+        // Each thread gets its own handle to the same vector
+        let child_numbers = Arc::clone(&shared_numbers);
+        joinhandles.push(thread::spawn(move || {
+            let sum: u32 = child_numbers.iter().filter(|&&n| n % 8 == offset).sum();
+            println!("Sum of offset {offset} is {sum}");
+        }));
+    }
+    for handle in joinhandles {
+        handle.join().unwrap();
+    }
+}
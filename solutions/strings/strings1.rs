@@ -0,0 +1,10 @@
+fn main() {
+    let answer = current_favorite_color();
+    println!("My current favorite color is {answer}");
+}
+
+// A string literal is a `&'static str`, a slice of a string that is stored
+// in the binary. `"blue".to_string()` would make a `String` out of it.
+fn current_favorite_color() -> &'static str {
+    "blue"
+}
@@ -0,0 +1,13 @@
+fn main() {
+    let word = String::from("green");
+    // `&word` borrows the `String`, which coerces to the `&str` the function takes
+    if is_a_color_word(&word) {
+        println!("That is a color word I know!");
+    } else {
+        println!("That is not a color word I know.");
+    }
+}
+
+fn is_a_color_word(attempt: &str) -> bool {
+    attempt == "green" || attempt == "blue" || attempt == "red"
+}
@@ -0,0 +1,39 @@
+fn trim_me(input: &str) -> String {
+    // `trim` returns a slice of the input, which is copied into a new `String`
+    input.trim().to_string()
+}
+
+fn compose_me(input: &str) -> String {
+    // `format!` is like `println!`, but gives back the `String` instead.
+    // `input.to_string() + " world!"` works too, adding a `&str` to a `String`.
+    format!("{input} world!")
+}
+
+fn replace_me(input: &str) -> String {
+    // Replacing can change the length, so it always makes a new `String`
+    input.replace("cars", "balloons")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trim_a_string() {
+        assert_eq!(trim_me("Hello!     "), "Hello!");
+        assert_eq!(trim_me("  What's up!"), "What's up!");
+        assert_eq!(trim_me("   Hola!  "), "Hola!");
+    }
+
+    #[test]
+    fn compose_a_string() {
+        assert_eq!(compose_me("Hello"), "Hello world!");
+        assert_eq!(compose_me("Goodbye"), "Goodbye world!");
+    }
+
+    #[test]
+    fn replace_a_string() {
+        assert_eq!(replace_me("I think cars are cool"), "I think balloons are cool");
+        assert_eq!(replace_me("I love to look at cars"), "I love to look at balloons");
+    }
+}
@@ -0,0 +1,9 @@
+#[cfg(test)]
+mod tests {
+    // `assert!` fails the test if its condition is false
+    #[test]
+    fn you_can_assert() {
+        let x = 1 + 1;
+        assert!(x == 2);
+    }
+}
@@ -0,0 +1,9 @@
+#[cfg(test)]
+mod tests {
+    // `assert_eq!` compares two values, and shows both of them if they differ
+    #[test]
+    fn you_can_assert_eq() {
+        let x = 1 + 1;
+        assert_eq!(x, 2);
+    }
+}
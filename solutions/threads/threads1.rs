@@ -0,0 +1,31 @@
+// Spawns threads that each run for at least 250ms, and collects how long they took
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+fn main() {
+    let mut handles = vec![];
+    for i in 0..10 {
+        handles.push(thread::spawn(move || {
+            let start = Instant::now();
+            thread::sleep(Duration::from_millis(250));
+            println!("thread {i} is complete");
+            start.elapsed().as_millis()
+        }));
+    }
+
+    // `join` waits for the thread to finish and gives back what its closure returned
+    let mut results = Vec::new();
+    for handle in handles {
+        results.push(handle.join().unwrap());
+    }
+
+    if results.len() != 10 {
+        panic!("Oh no! All the spawned threads did not finish!");
+    }
+
+    println!();
+    for (i, result) in results.into_iter().enumerate() {
+        println!("thread {i} took {result}ms");
+    }
+}
@@ -0,0 +1,63 @@
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+struct Queue {
+    length: u32,
+    first_half: Vec<u32>,
+    second_half: Vec<u32>,
+}
+
+impl Queue {
+    fn new() -> Self {
+        Queue {
+            length: 10,
+            first_half: vec![1, 2, 3, 4, 5],
+            second_half: vec![6, 7, 8, 9, 10],
+        }
+    }
+}
+
+fn send_tx(q: Queue, tx: mpsc::Sender<u32>) {
+    // Each thread needs a sender of its own, which `clone` gives.
+    // The receiver sees the end of the channel once both are dropped.
+    let tx1 = tx.clone();
+    let Queue {
+        first_half,
+        second_half,
+        ..
+    } = q;
+
+    thread::spawn(move || {
+        for val in first_half {
+            println!("sending {val:?}");
+            tx1.send(val).unwrap();
+            thread::sleep(Duration::from_millis(1));
+        }
+    });
+
+    thread::spawn(move || {
+        for val in second_half {
+            println!("sending {val:?}");
+            tx.send(val).unwrap();
+            thread::sleep(Duration::from_millis(1));
+        }
+    });
+}
+
+fn main() {
+    let (tx, rx) = mpsc::channel();
+    let queue = Queue::new();
+    let queue_length = queue.length;
+
+    send_tx(queue, tx);
+
+    let mut total_received: u32 = 0;
+    for received in rx {
+        println!("Got: {received}");
+        total_received += 1;
+    }
+
+    println!("total numbers received: {total_received}");
+    assert_eq!(total_received, queue_length);
+}
@@ -0,0 +1,5 @@
+fn main() {
+    // Variables are declared with the `let` keyword
+    let x = 5;
+    println!("x has the value {x}");
+}
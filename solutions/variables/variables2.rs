@@ -0,0 +1,10 @@
+fn main() {
+    // A variable has to be given a value before it can be read.
+    // Its type is inferred from that value, here `i32`.
+    let x = 42;
+    if x == 10 {
+        println!("x is ten!");
+    } else {
+        println!("x is not ten!");
+    }
+}
@@ -0,0 +1,6 @@
+fn main() {
+    // Reading a variable that was declared without a value doesn't compile,
+    // so it's initialized right away
+    let x: i32 = 42;
+    println!("Number {x}");
+}
@@ -0,0 +1,7 @@
+fn main() {
+    // Variables are immutable unless they are declared with `mut`
+    let mut x = 3;
+    println!("Number {x}");
+    x = 5;
+    println!("Number {x}");
+}
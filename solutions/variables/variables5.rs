@@ -0,0 +1,9 @@
+fn main() {
+    let number = "T-H-R-E-E";
+    println!("Spell a number: {number}");
+
+    // A new `let` shadows the previous variable of the same name,
+    // and the new one can have a different type
+    let number = 3;
+    println!("Number plus two is: {}", number + 2);
+}
@@ -0,0 +1,6 @@
+// Constants always need a type annotation, it's never inferred
+const NUMBER: u64 = 3;
+
+fn main() {
+    println!("Number: {NUMBER}");
+}
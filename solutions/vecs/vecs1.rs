@@ -0,0 +1,20 @@
+fn array_and_vec() -> ([i32; 4], Vec<i32>) {
+    let a = [10, 20, 30, 40]; // Array
+
+    // The `vec!` macro builds a vector out of the same syntax as an array.
+    // `a.to_vec()` or `Vec::from(a)` would work too.
+    let v = vec![10, 20, 30, 40];
+
+    (a, v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_array_and_vec_similarity() {
+        let (a, v) = array_and_vec();
+        assert_eq!(a, v[..]);
+    }
+}
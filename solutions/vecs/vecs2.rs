@@ -0,0 +1,35 @@
+fn vec_loop(mut v: Vec<i32>) -> Vec<i32> {
+    // `iter_mut` hands out mutable references to the elements,
+    // which have to be dereferenced with `*` to change the element
+    for element in v.iter_mut() {
+        *element *= 2;
+    }
+
+    v
+}
+
+fn vec_map(v: &[i32]) -> Vec<i32> {
+    // `map` transforms every element, `collect` gathers them into a new vector
+    v.iter().map(|element| element * 2).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vec_loop() {
+        let v: Vec<i32> = (1..).filter(|x| x % 2 == 0).take(5).collect();
+        let ans = vec_loop(v.clone());
+
+        assert_eq!(ans, v.iter().map(|x| x * 2).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn test_vec_map() {
+        let v: Vec<i32> = (1..).filter(|x| x % 2 == 0).take(5).collect();
+        let ans = vec_map(&v);
+
+        assert_eq!(ans, v.iter().map(|x| x * 2).collect::<Vec<i32>>());
+    }
+}
@@ -0,0 +1,110 @@
+use crate::exercise::Exercise;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+
+// Where cached results are kept, relative to the workspace root
+const CACHE_DIR: &str = "target/rustlings/cache";
+// Bumped whenever the format of the cached results changes
+const CACHE_VERSION: &str = "5";
+
+/// The key a result of the given exercise is cached under.
+///
+/// It covers everything the result depends on: the source of the exercise,
+/// its definition in `info.toml` (mode, timeout, ...), the expected outputs,
+/// the stdin fixtures, the toolchain and the version of rustlings, whose
+/// checks of the results may change.
+/// Returns `None` if the exercise can't be read, which disables caching.
+pub fn key(exercise: &Exercise) -> Option<String> {
+    let mut hasher = Sha256::new();
+    hasher.update(CACHE_VERSION);
+    hasher.update(env!("CARGO_PKG_VERSION"));
+    hasher.update(rustc_version());
+    hasher.update(format!("{exercise:?}"));
+    hasher.update(exercise.content_hash().ok()?);
+    if let Some(expected) = &exercise.expected_output {
+        hasher.update(expected.text().unwrap_or_default());
+    }
+    for case in &exercise.stdin {
+        hasher.update(case.input().unwrap_or_default());
+        if let Some(expected) = &case.expected_output {
+            hasher.update(expected.text().unwrap_or_default());
+        }
+    }
+    Some(format!("{:x}", hasher.finalize()))
+}
+
+/// Look up a cached result, a missing or unreadable entry is a miss
+pub fn load<T: DeserializeOwned>(key: &str) -> Option<T> {
+    let contents = fs::read(entry_path(key)).ok()?;
+    serde_json::from_slice(&contents).ok()
+}
+
+/// Cache a result under the given key, failures are silently ignored
+/// since the result can always be computed again
+pub fn store<T: Serialize>(key: &str, value: &T) {
+    let path = entry_path(key);
+    // Other processes may be storing the same entry at the same time
+    let tmp = path.with_extension(format!("json.{}.tmp", std::process::id()));
+    let write = || -> io::Result<()> {
+        fs::create_dir_all(CACHE_DIR)?;
+        fs::write(&tmp, serde_json::to_vec(value)?)?;
+        fs::rename(&tmp, &path)
+    };
+    let _ = write();
+}
+
+/// Remove every cached result
+pub fn clean() -> io::Result<()> {
+    if Path::new(CACHE_DIR).exists() {
+        fs::remove_dir_all(CACHE_DIR)?;
+    }
+    Ok(())
+}
+
+fn entry_path(key: &str) -> PathBuf {
+    Path::new(CACHE_DIR).join(format!("{key}.json"))
+}
+
+// The verbose version of the toolchain, which is part of every key
+fn rustc_version() -> &'static str {
+    static VERSION: OnceLock<String> = OnceLock::new();
+    VERSION.get_or_init(|| {
+        Command::new("rustc")
+            .args(["--version", "--verbose"])
+            .output()
+            .map(|output| String::from_utf8_lossy(&output.stdout).to_string())
+            .unwrap_or_default()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::exercise::{test_exercise, Mode};
+
+    #[test]
+    fn test_key_changes_with_inputs() {
+        let path = std::env::temp_dir().join(format!("rustlings_cache_{}.rs", std::process::id()));
+        fs::write(&path, "fn main() {}\n").unwrap();
+        let mut exercise = test_exercise("cached", path.clone(), Mode::Compile);
+
+        let original = key(&exercise).unwrap();
+        assert_eq!(key(&exercise).unwrap(), original);
+
+        exercise.mode = Mode::Test;
+        let test_mode = key(&exercise).unwrap();
+        assert_ne!(test_mode, original);
+
+        fs::write(&path, "fn main() { println!(); }\n").unwrap();
+        assert_ne!(key(&exercise).unwrap(), test_mode);
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(key(&exercise), None);
+    }
+}
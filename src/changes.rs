@@ -0,0 +1,222 @@
+use crate::diff::{self, Change};
+use crate::exercise::Exercise;
+use crate::pristine;
+use std::fs;
+
+// How many unchanged lines are shown around the changed ones
+const CONTEXT: usize = 3;
+
+/// Print how the learner's files of the exercise differ from the original ones.
+/// With `ignore_trivial`, changes to whitespace and comments are left out.
+/// Returns whether there were changes, failing if there is no original.
+pub fn show(exercise: &Exercise, ignore_trivial: bool) -> Result<bool, String> {
+    let files = pristine::files(exercise);
+    if files.is_empty() {
+        return Err(format!(
+            "There is no original copy of {exercise} to compare with"
+        ));
+    }
+
+    let mut changed = false;
+    for (path, original) in files {
+        let original = String::from_utf8_lossy(&original);
+        // A file the learner deleted shows up as all removed
+        let yours = fs::read_to_string(&path).unwrap_or_default();
+        let original = Lines::new(&original, ignore_trivial);
+        let yours = Lines::new(&yours, ignore_trivial);
+
+        let changes = diff::lines(&original.keys(), &yours.keys());
+        if changes.iter().all(|c| matches!(c, Change::Same(_))) {
+            continue;
+        }
+        changed = true;
+        let path = path.display();
+        print!(
+            "{}",
+            diff::render_hunks(
+                (&format!("{path} (original)"), &format!("{path} (yours)")),
+                &as_written(&changes, &original, &yours),
+                CONTEXT,
+                (&original.numbers, &yours.numbers),
+            )
+        );
+    }
+    Ok(changed)
+}
+
+// The lines of a file to compare, by their key.
+// When ignoring trivial changes, the key is the code of the line
+// and the lines that are only whitespace or comments are left out.
+struct Lines<'a> {
+    text: Vec<&'a str>,
+    keys: Vec<String>,
+    numbers: Vec<usize>,
+}
+
+impl<'a> Lines<'a> {
+    fn new(text: &'a str, ignore_trivial: bool) -> Self {
+        let mut lines = Lines {
+            text: Vec::new(),
+            keys: Vec::new(),
+            numbers: Vec::new(),
+        };
+        let mut code = Code::default();
+        for (i, line) in text.lines().enumerate() {
+            let key = if ignore_trivial {
+                code.of_line(line)
+            } else {
+                line.to_string()
+            };
+            if ignore_trivial && key.is_empty() {
+                continue;
+            }
+            lines.text.push(line);
+            lines.keys.push(key);
+            lines.numbers.push(i + 1);
+        }
+        lines
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.keys.iter().map(String::as_str).collect()
+    }
+}
+
+// The changes between the keys of lines, with the lines as they are written.
+// Unchanged lines are shown like the learner wrote them.
+fn as_written<'a>(changes: &[Change], original: &Lines<'a>, yours: &Lines<'a>) -> Vec<Change<'a>> {
+    let (mut old, mut new) = (0, 0);
+    changes
+        .iter()
+        .map(|change| match change {
+            Change::Same(_) => {
+                (old, new) = (old + 1, new + 1);
+                Change::Same(yours.text[new - 1])
+            }
+            Change::Removed(_) => {
+                old += 1;
+                Change::Removed(original.text[old - 1])
+            }
+            Change::Added(_) => {
+                new += 1;
+                Change::Added(yours.text[new - 1])
+            }
+        })
+        .collect()
+}
+
+// Strips Rust source of its comments and the whitespace between tokens,
+// a line at a time. Block comments and strings can span several lines.
+#[derive(Default)]
+struct Code {
+    // How deeply nested the block comment we're in is
+    comment_depth: usize,
+    in_string: bool,
+}
+
+impl Code {
+    fn of_line(&mut self, line: &str) -> String {
+        let chars: Vec<char> = line.chars().collect();
+        let mut code = String::new();
+        let mut space = false;
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+            if self.comment_depth > 0 {
+                match (c, next) {
+                    ('*', Some('/')) => {
+                        self.comment_depth -= 1;
+                        i += 1;
+                    }
+                    ('/', Some('*')) => {
+                        self.comment_depth += 1;
+                        i += 1;
+                    }
+                    _ => {}
+                }
+                space = true;
+            } else if self.in_string {
+                code.push(c);
+                if c == '\\' {
+                    code.extend(next);
+                    i += 1;
+                } else if c == '"' {
+                    self.in_string = false;
+                }
+            } else {
+                match (c, next) {
+                    ('/', Some('/')) => break,
+                    ('/', Some('*')) => {
+                        self.comment_depth = 1;
+                        space = true;
+                        i += 1;
+                    }
+                    (c, _) if c.is_whitespace() => space = true,
+                    _ => {
+                        // Whitespace only matters between words, like in `let x`
+                        if space && is_word(code.chars().last()) && is_word(Some(c)) {
+                            code.push(' ');
+                        }
+                        space = false;
+                        code.push(c);
+                        match (c, next, chars.get(i + 2)) {
+                            ('"', _, _) => self.in_string = true,
+                            // A char literal, which can be a quote
+                            ('\'', Some('\\'), _) => {
+                                if let Some(end) = (i + 3..chars.len()).find(|&j| chars[j] == '\'')
+                                {
+                                    code.extend(&chars[i + 1..=end]);
+                                    i = end;
+                                }
+                            }
+                            ('\'', Some(quoted), Some('\'')) => {
+                                code.push(quoted);
+                                code.push('\'');
+                                i += 2;
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            i += 1;
+        }
+        code
+    }
+}
+
+fn is_word(c: Option<char>) -> bool {
+    c.is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_code_without_trivia() {
+        let mut code = Code::default();
+        let lines = [
+            "// I AM NOT DONE",
+            "fn  main()  {",
+            "    let x=\"a // b\"; /* start",
+            "    still a comment */ let c = '\"';",
+            "let q = '\\''; // a quote",
+            "}",
+        ]
+        .map(|line| code.of_line(line));
+        assert_eq!(
+            lines,
+            [
+                "",
+                "fn main(){",
+                "let x=\"a // b\";",
+                "let c='\"';",
+                "let q='\\'';",
+                "}"
+            ]
+            .map(String::from)
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use crate::exercise::{self, Exercise, ExerciseList, Mode, State};
+use crate::expected::{ExpectedOutput, Source};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const INFO_FILE: &str = "info.toml";
+const EXERCISES_DIR: &str = "exercises";
+
+/// A problem with the exercises, at a line of info.toml if it's in there
+#[derive(PartialEq, Debug)]
+pub struct Problem {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Problem {
+    fn at(line: usize, message: String) -> Self {
+        Problem {
+            line: Some(line),
+            message,
+        }
+    }
+
+    fn in_tree(message: String) -> Self {
+        Problem {
+            line: None,
+            message,
+        }
+    }
+}
+
+/// Check info.toml and the exercises in the current directory, printing
+/// the problems found. Returns how many exercises there are if there are
+/// no problems, or else how many problems there are.
+pub fn check() -> Result<usize, usize> {
+    let (exercises, problems) = match fs::read_to_string(INFO_FILE) {
+        Ok(text) => check_manifest(&text),
+        Err(e) => (
+            0,
+            vec![Problem::in_tree(format!("Failed to read {INFO_FILE}: {e}"))],
+        ),
+    };
+    for problem in &problems {
+        match problem.line {
+            Some(line) => println!("{INFO_FILE}:{line}: {}", problem.message),
+            None => println!("{}", problem.message),
+        }
+    }
+    if problems.is_empty() {
+        Ok(exercises)
+    } else {
+        Err(problems.len())
+    }
+}
+
+// The number of exercises in info.toml, with the text it was read from,
+// and the problems with it and the exercises it lists
+fn check_manifest(text: &str) -> (usize, Vec<Problem>) {
+    let lines = Lines::new(text);
+    let exercises = match toml::from_str::<ExerciseList>(text) {
+        Ok(list) => list.into_exercises(),
+        Err(e) => return (0, vec![parse_problem(text, &lines, e)]),
+    };
+    let mut problems = Vec::new();
+    let mut names = HashMap::new();
+
+    for (i, exercise) in exercises.iter().enumerate() {
+        let name = &exercise.name;
+        if let Some(first) = names.insert(name.clone(), lines.of(i, "name")) {
+            problems.push(Problem::at(
+                lines.of(i, "name"),
+                format!("The name {name} is already used by the exercise at line {first}"),
+            ));
+        }
+
+        if exercise.hint.levels().is_empty()
+            || exercise.hint.levels().iter().any(|h| h.trim().is_empty())
+        {
+            problems.push(Problem::at(
+                lines.of(i, "hint"),
+                format!("{name} has an empty hint"),
+            ));
+        }
+
+        // Compiling without errors fails it, so it needs some errors to pass
+        if let (Mode::CompileFail, []) = (exercise.mode, exercise.expected_errors.as_slice()) {
+            problems.push(Problem::at(
+                lines.of(i, "mode"),
+                format!("{name} is a compile_fail exercise with no expected_errors, it can't pass"),
+            ));
+        }
+
+        let path_exists = match exercise.mode {
+            Mode::Cargo => exercise.path.join("Cargo.toml").is_file(),
+            _ => exercise.path.is_file(),
+        };
+        if !path_exists {
+            let what = match exercise.mode {
+                Mode::Cargo => "crate",
+                _ => "file",
+            };
+            problems.push(Problem::at(
+                lines.of(i, "path"),
+                format!(
+                    "The {what} {} of {name} doesn't exist",
+                    exercise.path.display()
+                ),
+            ));
+        } else if exercise.state() == State::Done {
+            problems.push(Problem::at(
+                lines.of(i, "path"),
+                format!("{name} has no `I AM NOT DONE` marker"),
+            ));
+        }
+        if same_as_solution(exercise) {
+            problems.push(Problem::at(
+                lines.of(i, "path"),
+                format!("{name} is the same as its solution, which should be finished"),
+            ));
+        }
+
+        for file in data_files(exercise) {
+            if !file.is_file() {
+                problems.push(Problem::at(
+                    lines.of(i, "name"),
+                    format!("The file {} of {name} doesn't exist", file.display()),
+                ));
+            }
+        }
+    }
+
+    problems.sort_by_key(|problem| problem.line);
+
+    if Path::new(EXERCISES_DIR).is_dir() {
+        problems.extend(check_tree(&exercises).unwrap_or_else(|e| {
+            vec![Problem::in_tree(format!(
+                "Failed to read the {EXERCISES_DIR} directory: {e}"
+            ))]
+        }));
+    }
+    (exercises.len(), problems)
+}
+
+// Where info.toml failed to parse. toml only knows the line of syntax errors,
+// the entries are deserialized one by one to find which one is wrong.
+fn parse_problem(text: &str, lines: &Lines, error: toml::de::Error) -> Problem {
+    let entries = toml::from_str::<toml::Value>(text)
+        .ok()
+        .and_then(|value| value.get("exercises")?.as_array().cloned())
+        .unwrap_or_default();
+    for (i, entry) in entries.into_iter().enumerate() {
+        let name = entry.get("name").and_then(toml::Value::as_str).map_or_else(
+            || format!("The exercise at line {}", lines.header(i)),
+            String::from,
+        );
+        if let Err(e) = entry.try_into::<Exercise>() {
+            let message = e.to_string();
+            // Like "missing field `hint`" or "... for key `mode`"
+            let key = message.rsplit('`').nth(1).unwrap_or_default();
+            return Problem::at(lines.of(i, key), format!("{name}: {message}"));
+        }
+    }
+    // The line and column are in the message too
+    let line = error.line_col().map_or(1, |(line, _)| line + 1);
+    Problem::at(line, error.to_string())
+}
+
+// The files of the exercises directory that no exercise uses,
+// and its directories without a README.md
+fn check_tree(exercises: &[Exercise]) -> io::Result<Vec<Problem>> {
+    let mut used = Vec::new();
+    let mut crates = Vec::new();
+    for exercise in exercises {
+        let path = exercise.path.canonicalize().unwrap_or_default();
+        if let Mode::Cargo = exercise.mode {
+            crates.push(path);
+        } else {
+            used.push(path);
+        }
+        used.extend(data_files(exercise).filter_map(|file| file.canonicalize().ok()));
+    }
+    let in_crate = |path: &Path| crates.iter().any(|dir| path.starts_with(dir));
+
+    let mut problems = Vec::new();
+    let mut checked_dirs = HashSet::new();
+    for path in exercise::crate_files(Path::new(EXERCISES_DIR))? {
+        // Check the directories on the way to the file first, top down
+        let mut dirs: Vec<&Path> = path
+            .ancestors()
+            .skip(1)
+            .take_while(|dir| dir.starts_with(EXERCISES_DIR))
+            .collect();
+        dirs.reverse();
+        for dir in dirs {
+            // The directories of a crate are up to its exercise
+            if checked_dirs.insert(dir.to_path_buf())
+                && !in_crate(&dir.canonicalize()?)
+                && !dir.join("README.md").is_file()
+            {
+                problems.push(Problem::in_tree(format!(
+                    "{} has no README.md",
+                    dir.display()
+                )));
+            }
+        }
+        // The files of a crate are all used by its exercise
+        let canonical = path.canonicalize()?;
+        if !in_crate(&canonical) && !path.ends_with("README.md") && !used.contains(&canonical) {
+            problems.push(Problem::in_tree(format!(
+                "{} isn't used by any exercise in {INFO_FILE}",
+                path.display()
+            )));
+        }
+    }
+    Ok(problems)
+}
+
+// Whether the solution of the exercise is a copy of it, file for file
+fn same_as_solution(exercise: &Exercise) -> bool {
+    let contents = |path: &Path| -> Option<Vec<(PathBuf, Vec<u8>)>> {
+        let files = match exercise.mode {
+            Mode::Cargo => exercise::crate_files(path).ok()?,
+            _ => vec![path.to_path_buf()],
+        };
+        files
+            .into_iter()
+            .map(|file| {
+                Some((
+                    file.strip_prefix(path).ok()?.to_path_buf(),
+                    fs::read(&file).ok()?,
+                ))
+            })
+            .collect()
+    };
+    match exercise
+        .solution_path()
+        .and_then(|solution| contents(&solution))
+    {
+        Some(solution) => contents(&exercise.path) == Some(solution),
+        None => false,
+    }
+}
+
+// The files with the input and the expected output of an exercise
+fn data_files(exercise: &Exercise) -> impl Iterator<Item = PathBuf> + '_ {
+    let expected_file = |expected: &Option<ExpectedOutput>| match expected {
+        Some(ExpectedOutput {
+            source: Source::File(file),
+            ..
+        }) => Some(file.clone()),
+        _ => None,
+    };
+    expected_file(&exercise.expected_output)
+        .into_iter()
+        .chain(exercise.stdin.iter().flat_map(move |case| {
+            case.input_file
+                .clone()
+                .into_iter()
+                .chain(expected_file(&case.expected_output))
+        }))
+}
+
+// Where the entries of info.toml and their keys are,
+// as toml doesn't tell where it deserialized them from
+struct Lines<'a> {
+    lines: Vec<&'a str>,
+    // The line of the `[[exercises]]` header of every entry
+    headers: Vec<usize>,
+}
+
+impl<'a> Lines<'a> {
+    fn new(text: &'a str) -> Self {
+        let lines: Vec<&str> = text.lines().collect();
+        let headers = (0..lines.len())
+            .filter(|&i| lines[i].trim() == "[[exercises]]")
+            .collect();
+        Lines { lines, headers }
+    }
+
+    // The number of the line with the header of an entry
+    fn header(&self, entry: usize) -> usize {
+        self.headers.get(entry).map_or(1, |header| header + 1)
+    }
+
+    // The number of the line with the key of an entry,
+    // or of its header if the key isn't there
+    fn of(&self, entry: usize, key: &str) -> usize {
+        let Some(&header) = self.headers.get(entry) else {
+            return 1;
+        };
+        let end = self
+            .lines
+            .iter()
+            .enumerate()
+            .skip(header + 1)
+            .find(|(_, line)| line.trim_start().starts_with('['))
+            .map_or(self.lines.len(), |(i, _)| i);
+        (header + 1..end)
+            .find(|&i| {
+                self.lines[i]
+                    .trim_start()
+                    .strip_prefix(key)
+                    .is_some_and(|rest| rest.trim_start().starts_with('='))
+            })
+            .unwrap_or(header)
+            + 1
+    }
+}
@@ -0,0 +1,251 @@
+use console::style;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter, Write};
+
+/// A diagnostic emitted by rustc or clippy with `--error-format=json`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Diagnostic {
+    /// The primary message
+    pub message: String,
+    /// The error or lint code, like `E0382` or `clippy::float_cmp`
+    pub code: Option<DiagnosticCode>,
+    pub level: Level,
+    /// Where in the source the diagnostic applies
+    #[serde(default)]
+    pub spans: Vec<Span>,
+    /// Attached notes, helps and suggestions
+    #[serde(default)]
+    pub children: Vec<Diagnostic>,
+    /// The diagnostic as rustc would have printed it, including colors
+    pub rendered: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DiagnosticCode {
+    pub code: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum Level {
+    Error,
+    Warning,
+    Note,
+    Help,
+    FailureNote,
+    #[serde(rename = "error: internal compiler error")]
+    Ice,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Span {
+    pub file_name: String,
+    pub line_start: usize,
+    pub column_start: usize,
+    /// Whether this is the span the diagnostic is about,
+    /// or just an additional one for context
+    pub is_primary: bool,
+    pub label: Option<String>,
+    /// The code rustc suggests to replace this span with
+    pub suggested_replacement: Option<String>,
+}
+
+/// The number of errors and warnings in some diagnostics
+#[derive(Default, PartialEq, Debug)]
+pub struct Summary {
+    pub errors: usize,
+    pub warnings: usize,
+}
+
+impl Diagnostic {
+    /// Whether this is an actual error or warning about the code,
+    /// and not something like "aborting due to 2 previous errors"
+    fn is_about_code(&self) -> bool {
+        !self.spans.is_empty()
+    }
+}
+
+/// Parse the stderr of rustc run with `--error-format=json`.
+/// Returns the diagnostics and every line that isn't one, like linker errors.
+pub fn parse_rustc(stderr: &str) -> (Vec<Diagnostic>, String) {
+    let mut diagnostics = Vec::new();
+    let mut other = String::new();
+    for line in stderr.lines() {
+        match serde_json::from_str::<Diagnostic>(line) {
+            Ok(diagnostic) => diagnostics.push(diagnostic),
+            Err(_) => {
+                other.push_str(line);
+                other.push('\n');
+            }
+        }
+    }
+    (diagnostics, other)
+}
+
+/// Parse the stdout of cargo run with `--message-format=json`,
+/// keeping only the diagnostics of the compiler
+pub fn parse_cargo(stdout: &str) -> Vec<Diagnostic> {
+    #[derive(Deserialize)]
+    struct Message {
+        reason: String,
+        message: Option<Diagnostic>,
+    }
+
+    stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Message>(line).ok())
+        .filter(|message| message.reason == "compiler-message")
+        .filter_map(|message| message.message)
+        .collect()
+}
+
+/// Count the errors and warnings about the code
+pub fn summarize(diagnostics: &[Diagnostic]) -> Summary {
+    let mut summary = Summary::default();
+    for diagnostic in diagnostics.iter().filter(|d| d.is_about_code()) {
+        match diagnostic.level {
+            Level::Error | Level::Ice => summary.errors += 1,
+            Level::Warning => summary.warnings += 1,
+            _ => {}
+        }
+    }
+    summary
+}
+
+/// The codes of the errors about the code, sorted and without duplicates.
+/// Errors without a code, like syntax errors, are represented by their message.
+pub fn error_codes(diagnostics: &[Diagnostic]) -> Vec<String> {
+    let mut codes: Vec<String> = diagnostics
+        .iter()
+        .filter(|d| d.is_about_code() && matches!(d.level, Level::Error | Level::Ice))
+        .map(|d| match &d.code {
+            Some(code) => code.code.clone(),
+            None => d.message.clone(),
+        })
+        .collect();
+    codes.sort();
+    codes.dedup();
+    codes
+}
+
+/// Render the diagnostics the way rustc prints them.
+/// Diagnostics that rustc didn't render are printed in a short form.
+pub fn render(diagnostics: &[Diagnostic]) -> String {
+    let mut out = String::new();
+    for diagnostic in diagnostics {
+        match &diagnostic.rendered {
+            Some(rendered) => out.push_str(rendered),
+            None => render_short(&mut out, diagnostic, 0),
+        }
+    }
+    out
+}
+
+fn render_short(out: &mut String, diagnostic: &Diagnostic, depth: usize) {
+    let indent = "  ".repeat(depth);
+    let level = match diagnostic.level {
+        Level::Error | Level::Ice => style("error").red().bold(),
+        Level::Warning => style("warning").yellow().bold(),
+        Level::Note => style("note").bold(),
+        Level::Help => style("help").cyan().bold(),
+        Level::FailureNote | Level::Other => style("").bold(),
+    };
+    let code = match &diagnostic.code {
+        Some(code) => format!("[{}]", code.code),
+        None => String::new(),
+    };
+    let _ = writeln!(out, "{indent}{level}{code}: {}", diagnostic.message);
+    for span in &diagnostic.spans {
+        let marker = if span.is_primary { "-->" } else { "   " };
+        let _ = write!(
+            out,
+            "{indent}  {} {}:{}:{}",
+            style(marker).blue().bold(),
+            span.file_name,
+            span.line_start,
+            span.column_start
+        );
+        if let Some(label) = &span.label {
+            let _ = write!(out, " {label}");
+        }
+        if let Some(replacement) = &span.suggested_replacement {
+            let _ = write!(out, " `{replacement}`");
+        }
+        out.push('\n');
+    }
+    for child in &diagnostic.children {
+        render_short(out, child, depth + 1);
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let plural = |n: usize| if n == 1 { "" } else { "s" };
+        write!(f, "{} error{}", self.errors, plural(self.errors))?;
+        if self.warnings > 0 {
+            write!(f, ", {} warning{}", self.warnings, plural(self.warnings))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RUSTC_STDERR: &str = r#"{"$message_type":"diagnostic","message":"mismatched types","code":{"code":"E0308","explanation":null},"level":"error","spans":[{"file_name":"d.rs","byte_start":25,"byte_end":28,"line_start":1,"line_end":1,"column_start":26,"column_end":29,"is_primary":true,"text":[],"label":"expected `i32`, found `&str`","suggested_replacement":null,"suggestion_applicability":null,"expansion":null}],"children":[],"rendered":"error[E0308]: mismatched types\n"}
+{"$message_type":"diagnostic","message":"unused variable: `y`","code":{"code":"unused_variables","explanation":null},"level":"warning","spans":[{"file_name":"d.rs","byte_start":34,"byte_end":35,"line_start":1,"line_end":1,"column_start":35,"column_end":36,"is_primary":true,"text":[],"label":null,"suggested_replacement":null,"suggestion_applicability":null,"expansion":null}],"children":[{"message":"if this is intentional, prefix it with an underscore","code":null,"level":"help","spans":[{"file_name":"d.rs","byte_start":34,"byte_end":35,"line_start":1,"line_end":1,"column_start":35,"column_end":36,"is_primary":true,"text":[],"label":null,"suggested_replacement":"_y","suggestion_applicability":"MaybeIncorrect","expansion":null}],"children":[],"rendered":null}],"rendered":"warning: unused variable: `y`\n"}
+{"$message_type":"diagnostic","message":"aborting due to 1 previous error","code":null,"level":"error","spans":[],"children":[],"rendered":"error: aborting due to 1 previous error\n"}
+error: linking with `cc` failed"#;
+
+    #[test]
+    fn test_parse_rustc() {
+        let (diagnostics, other) = parse_rustc(RUSTC_STDERR);
+        assert_eq!(diagnostics.len(), 3);
+        assert_eq!(other, "error: linking with `cc` failed\n");
+
+        let error = &diagnostics[0];
+        assert_eq!(error.level, Level::Error);
+        assert_eq!(error.code.as_ref().unwrap().code, "E0308");
+        assert_eq!(error.spans[0].line_start, 1);
+        assert_eq!(error.spans[0].column_start, 26);
+
+        let help = &diagnostics[1].children[0];
+        assert_eq!(help.level, Level::Help);
+        assert_eq!(help.spans[0].suggested_replacement.as_deref(), Some("_y"));
+    }
+
+    #[test]
+    fn test_summarize_and_render() {
+        let (diagnostics, _) = parse_rustc(RUSTC_STDERR);
+        let summary = summarize(&diagnostics);
+        assert_eq!(
+            summary,
+            Summary {
+                errors: 1,
+                warnings: 1
+            }
+        );
+        assert_eq!(summary.to_string(), "1 error, 1 warning");
+        assert_eq!(error_codes(&diagnostics), ["E0308"]);
+        assert_eq!(
+            render(&diagnostics),
+            "error[E0308]: mismatched types\nwarning: unused variable: `y`\nerror: aborting due to 1 previous error\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_cargo() {
+        let stdout = r#"{"reason":"compiler-artifact","package_id":"clippy1","target":{},"filenames":[]}
+{"reason":"compiler-message","package_id":"clippy1","message":{"message":"strict comparison of `f32`","code":{"code":"clippy::float_cmp","explanation":null},"level":"error","spans":[],"children":[],"rendered":"error: strict comparison\n"}}
+{"reason":"build-finished","success":false}"#;
+        let diagnostics = parse_cargo(stdout);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].code.as_ref().unwrap().code,
+            "clippy::float_cmp"
+        );
+    }
+}
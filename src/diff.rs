@@ -0,0 +1,186 @@
+use console::style;
+use std::fmt::Write;
+
+// Above this many pairs of lines to compare, the diff just shows
+// everything that differs as removed and added instead of aligning it
+const MAX_DIFF_CELLS: usize = 1 << 22;
+
+/// A line of a diff
+#[derive(PartialEq, Debug)]
+pub enum Change<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// A minimal line diff from the longest common subsequence
+pub fn lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<Change<'a>> {
+    let prefix = old.iter().zip(new).take_while(|(o, n)| o == n).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(o, n)| o == n)
+        .count();
+    let removed = &old[prefix..old.len() - suffix];
+    let added = &new[prefix..new.len() - suffix];
+
+    let mut changes: Vec<Change> = old[..prefix].iter().map(|l| Change::Same(l)).collect();
+    if removed.len() * added.len() > MAX_DIFF_CELLS {
+        changes.extend(removed.iter().map(|l| Change::Removed(l)));
+        changes.extend(added.iter().map(|l| Change::Added(l)));
+    } else {
+        // lcs[i][j] is the length of the longest common subsequence of removed[i..] and added[j..]
+        let mut lcs = vec![vec![0usize; added.len() + 1]; removed.len() + 1];
+        for i in (0..removed.len()).rev() {
+            for j in (0..added.len()).rev() {
+                lcs[i][j] = if removed[i] == added[j] {
+                    lcs[i + 1][j + 1] + 1
+                } else {
+                    lcs[i + 1][j].max(lcs[i][j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < removed.len() || j < added.len() {
+            if i < removed.len() && j < added.len() && removed[i] == added[j] {
+                changes.push(Change::Same(removed[i]));
+                i += 1;
+                j += 1;
+            } else if j == added.len() || (i < removed.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+                changes.push(Change::Removed(removed[i]));
+                i += 1;
+            } else {
+                changes.push(Change::Added(added[j]));
+                j += 1;
+            }
+        }
+    }
+    changes.extend(old[old.len() - suffix..].iter().map(|l| Change::Same(l)));
+    changes
+}
+
+/// Render a diff in colour, under a header naming the old and the new side
+pub fn render(labels: (&str, &str), changes: &[Change]) -> String {
+    let mut out = header(labels);
+    for change in changes {
+        push_line(&mut out, change);
+    }
+    out
+}
+
+/// Render only the changed lines of a diff and `context` lines around them,
+/// in hunks like the ones of a unified diff. `numbers` are the line numbers
+/// of the old and the new lines the diff was made from, in order.
+pub fn render_hunks(
+    labels: (&str, &str),
+    changes: &[Change],
+    context: usize,
+    numbers: (&[usize], &[usize]),
+) -> String {
+    let mut out = header(labels);
+    let changed: Vec<usize> = (0..changes.len())
+        .filter(|&i| !matches!(changes[i], Change::Same(_)))
+        .collect();
+    // How many old and new lines come before each change
+    let mut before = Vec::with_capacity(changes.len() + 1);
+    let (mut old, mut new) = (0, 0);
+    for change in changes {
+        before.push((old, new));
+        match change {
+            Change::Same(_) => (old, new) = (old + 1, new + 1),
+            Change::Removed(_) => old += 1,
+            Change::Added(_) => new += 1,
+        }
+    }
+    before.push((old, new));
+
+    let mut rest = &changed[..];
+    while let Some(&first) = rest.first() {
+        let count = rest
+            .windows(2)
+            .take_while(|pair| pair[1] - pair[0] <= 2 * context + 1)
+            .count();
+        let last = rest[count];
+        rest = &rest[count + 1..];
+        let start = first.saturating_sub(context);
+        let end = (last + context + 1).min(changes.len());
+
+        let old_span = span(numbers.0, before[start].0, before[end].0);
+        let new_span = span(numbers.1, before[start].1, before[end].1);
+        let _ = writeln!(
+            out,
+            "{}",
+            style(format!("@@ -{old_span} +{new_span} @@")).cyan()
+        );
+        for change in &changes[start..end] {
+            push_line(&mut out, change);
+        }
+    }
+    out
+}
+
+// The lines from `start` to `end` as `first,count` in a hunk header,
+// where an empty span is placed after the line before it
+fn span(numbers: &[usize], start: usize, end: usize) -> String {
+    if start == end {
+        let before = start.checked_sub(1).map_or(0, |i| numbers[i]);
+        return format!("{before},0");
+    }
+    let first = numbers[start];
+    format!("{first},{}", numbers[end - 1] - first + 1)
+}
+
+fn header((old, new): (&str, &str)) -> String {
+    format!(
+        "{}\n{}\n",
+        style(format!("- {old}")).red(),
+        style(format!("+ {new}")).green()
+    )
+}
+
+fn push_line(out: &mut String, change: &Change) {
+    let _ = match change {
+        Change::Same(line) => writeln!(out, "  {line}"),
+        Change::Removed(line) => writeln!(out, "{}", style(format!("- {line}")).red()),
+        Change::Added(line) => writeln!(out, "{}", style(format!("+ {line}")).green()),
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines() {
+        let changes = lines(&["a", "b", "c", "d"], &["a", "c", "x", "d"]);
+        assert_eq!(
+            changes,
+            [
+                Change::Same("a"),
+                Change::Removed("b"),
+                Change::Same("c"),
+                Change::Added("x"),
+                Change::Same("d"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_hunks() {
+        console::set_colors_enabled(false);
+        let old: Vec<String> = (1..=20).map(|i| i.to_string()).collect();
+        let mut new = old.clone();
+        new[1] = "two".to_string();
+        new.remove(15);
+        let old: Vec<&str> = old.iter().map(String::as_str).collect();
+        let new: Vec<&str> = new.iter().map(String::as_str).collect();
+        let numbers: Vec<usize> = (1..=20).collect();
+
+        let rendered = render_hunks(("old", "new"), &lines(&old, &new), 1, (&numbers, &numbers));
+        assert_eq!(
+            rendered,
+            "- old\n+ new\n@@ -1,3 +1,3 @@\n  1\n- 2\n+ two\n  3\n@@ -15,3 +15,2 @@\n  15\n- 16\n  17\n"
+        );
+    }
+}
@@ -1,32 +1,70 @@
+use crate::diagnostics::{self, Diagnostic};
+use crate::expected::ExpectedOutput;
+use crate::session;
 use regex::Regex;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::env;
 use std::fmt::{self, Display, Formatter};
-use std::fs::{self, remove_file, File};
-use std::io::Read;
-use std::path::PathBuf;
-use std::process::{self, Command};
+use std::fs::{self, remove_file};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
 const RUSTC_COLOR_ARGS: &[&str] = &["--color", "always"];
-const RUSTC_EDITION_ARGS: &[&str] = &["--edition", "2021"];
+const RUSTC_JSON_ARGS: &[&str] = &["--error-format=json", "--json=diagnostic-rendered-ansi"];
+const CARGO_JSON_ARGS: &[&str] = &["--message-format=json-diagnostic-rendered-ansi"];
+pub const DEFAULT_EDITION: &str = "2021";
 const I_AM_DONE_REGEX: &str = r"(?m)^\s*///?\s*I\s+AM\s+NOT\s+DONE";
 const CONTEXT: usize = 2;
-const CLIPPY_CARGO_TOML_PATH: &str = "./exercises/clippy/Cargo.toml";
+const DEFAULT_TIMEOUT_SECS: u64 = 10;
+// rustdoc compiles the examples of doctest exercises while running them,
+// which gets this much time on top of the exercise's own time limit
+const DOCTEST_COMPILE_SECS: u64 = 60;
+const MEMORY_LIMIT_BYTES: u64 = 2 << 30;
+const FILE_SIZE_LIMIT_BYTES: u64 = 16 << 20;
+const OUTPUT_LIMIT_BYTES: u64 = 1 << 20;
+// Reading what a binary wrote before it was killed gets at least this long
+const KILLED_OUTPUT_GRACE_MILLIS: u64 = 100;
+// Cargo and clippy exercises are built by cargo in a directory of their own
+// in here, kept apart from the cache and the sessions which live here too
+const CARGO_TARGET_DIR: &str = "target/rustlings";
+// Where the reference solutions are, mirroring the exercises directory.
+// They are shipped embedded in rustlings rather than in the workspace.
+pub const SOLUTIONS_DIR: &str = "solutions";
 
-// Get a temporary file name that is hopefully unique
+// Get a temporary file name in the session directory, unique to the thread
 #[inline]
 fn temp_file() -> String {
-    let thread_id: String = format!("{:?}", std::thread::current().id())
+    session::dir()
+        .join(format!("temp_{}", thread_id()))
+        .to_string_lossy()
+        .into_owned()
+}
+
+// The library of a doctest exercise, which rustdoc only takes
+// with a file name like the ones of libraries built by cargo
+fn temp_rlib() -> String {
+    session::dir()
+        .join(format!("libtemp_{}.rlib", thread_id()))
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn thread_id() -> String {
+    format!("{:?}", thread::current().id())
         .chars()
         .filter(|c| c.is_alphanumeric())
-        .collect();
-
-    format!("./temp_{}_{thread_id}", process::id())
+        .collect()
 }
 
 // The mode of the exercise.
-#[derive(Deserialize, Copy, Clone, Debug)]
-#[serde(rename_all = "lowercase")]
+#[derive(Serialize, Deserialize, Copy, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
 pub enum Mode {
     // Indicates that the exercise should be compiled as a binary
     Compile,
@@ -34,13 +72,123 @@ pub enum Mode {
     Test,
     // Indicates that the exercise should be linted with clippy
     Clippy,
+    // Indicates that the exercise is a directory with a Cargo.toml,
+    // whose test harnesses should be built with cargo and run
+    Cargo,
+    // Indicates that the exercise should fail to compile,
+    // with exactly the errors in its expected_errors
+    CompileFail,
+    // Indicates that the exercise is a library whose documentation
+    // examples should be compiled and run by rustdoc
+    Doctest,
 }
 
 #[derive(Deserialize)]
 pub struct ExerciseList {
+    // What the pack of these exercises is, if it's one
+    pub pack: Option<PackInfo>,
+    // Settings for all exercises that don't have their own
+    #[serde(default)]
+    pub defaults: Defaults,
     pub exercises: Vec<Exercise>,
 }
 
+impl ExerciseList {
+    // The exercises, with the defaults filled in
+    pub fn into_exercises(self) -> Vec<Exercise> {
+        let defaults = self.defaults;
+        self.exercises
+            .into_iter()
+            .map(|mut exercise| {
+                if exercise.lints.is_none() {
+                    exercise.lints = defaults.lints.clone();
+                }
+                if exercise.edition.is_none() {
+                    exercise.edition = defaults.edition.clone();
+                }
+                if exercise.rustc_args.is_none() {
+                    exercise.rustc_args = defaults.rustc_args.clone();
+                }
+                if exercise.cfg.is_none() {
+                    exercise.cfg = defaults.cfg.clone();
+                }
+                exercise
+            })
+            .collect()
+    }
+}
+
+// The `[pack]` table of info.toml, which makes
+// the exercises a pack that can be installed
+#[derive(Deserialize, Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct PackInfo {
+    pub name: String,
+    pub version: Option<String>,
+}
+
+// The `[defaults]` table of info.toml
+#[derive(Deserialize, Default, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Defaults {
+    pub lints: Option<Lints>,
+    pub edition: Option<String>,
+    pub rustc_args: Option<Vec<String>>,
+    pub cfg: Option<Vec<String>>,
+}
+
+// The lints clippy checks a clippy exercise with.
+// Later levels win, so specific lints can be allowed in a denied group.
+#[derive(Deserialize, Clone, Default, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Lints {
+    #[serde(default)]
+    pub deny: Vec<String>,
+    #[serde(default)]
+    pub warn: Vec<String>,
+    #[serde(default)]
+    pub allow: Vec<String>,
+}
+
+impl Lints {
+    // The lints of exercises that don't choose any
+    fn builtin() -> Self {
+        Lints {
+            deny: vec!["warnings".to_string(), "clippy::float_cmp".to_string()],
+            ..Lints::default()
+        }
+    }
+
+    // The arguments passed on to clippy
+    fn args(&self) -> Vec<&str> {
+        let levels = [("-D", &self.deny), ("-W", &self.warn), ("-A", &self.allow)];
+        levels
+            .into_iter()
+            .flat_map(|(flag, lints)| lints.iter().flat_map(move |lint| [flag, lint.as_str()]))
+            .collect()
+    }
+}
+
+impl Display for Lints {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let levels = [
+            ("deny", &self.deny),
+            ("warn", &self.warn),
+            ("allow", &self.allow),
+        ];
+        let levels: Vec<String> = levels
+            .into_iter()
+            .filter(|(_, lints)| !lints.is_empty())
+            .map(|(level, lints)| format!("{level} {}", lints.join(", ")))
+            .collect();
+        if levels.is_empty() {
+            write!(f, "clippy's defaults")
+        } else {
+            write!(f, "{}", levels.join("; "))
+        }
+    }
+}
+
 // A representation of a rustlings exercise.
 // This is deserialized from the accompanying info.toml file
 #[derive(Deserialize, Debug)]
@@ -51,8 +199,100 @@ pub struct Exercise {
     pub path: PathBuf,
     // The mode of the exercise (Test, Compile, or Clippy)
     pub mode: Mode,
-    // The hint text associated with the exercise
-    pub hint: String,
+    // The hints associated with the exercise, revealed one level at a time
+    pub hint: Hints,
+    // How long the compiled binary may run before it is killed,
+    // defaults to DEFAULT_TIMEOUT_SECS
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    // What the binary has to print, only checked in compile mode
+    #[serde(default)]
+    pub expected_output: Option<ExpectedOutput>,
+    // Inputs the binary is run with one after the other, only in compile mode.
+    // Without any, the binary is run once with an empty stdin.
+    #[serde(default)]
+    pub stdin: Vec<StdinCase>,
+    // The error codes a compile_fail exercise has to fail with, like "E0382"
+    #[serde(default)]
+    pub expected_errors: Vec<String>,
+    // The lints of a clippy exercise, defaults to denying all warnings
+    #[serde(default)]
+    pub lints: Option<Lints>,
+    // The edition the exercise is compiled with, defaults to DEFAULT_EDITION
+    #[serde(default)]
+    pub edition: Option<String>,
+    // Extra arguments for rustc, like "-C" and "overflow-checks=off".
+    // Cargo exercises take these from their own manifest instead.
+    #[serde(default)]
+    pub rustc_args: Option<Vec<String>>,
+    // Configuration options the exercise is compiled with, like `--cfg` does
+    #[serde(default)]
+    pub cfg: Option<Vec<String>>,
+    // The reference solution, a directory for cargo exercises. Defaults to
+    // the path of the exercise with solutions/ in place of exercises/.
+    #[serde(default)]
+    pub solution: Option<PathBuf>,
+}
+
+// The hints of an exercise, from the least to the most revealing.
+// In info.toml, this is a single hint or a list of them.
+#[derive(Deserialize, Clone, Default, Debug)]
+#[serde(from = "RawHints")]
+pub struct Hints(Vec<String>);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawHints {
+    Single(String),
+    Levels(Vec<String>),
+}
+
+impl From<RawHints> for Hints {
+    fn from(raw: RawHints) -> Self {
+        match raw {
+            RawHints::Single(hint) => Hints(vec![hint]),
+            RawHints::Levels(hints) => Hints(hints),
+        }
+    }
+}
+
+impl Hints {
+    // The hints by level, the first one being level 1
+    pub fn levels(&self) -> &[String] {
+        &self.0
+    }
+}
+
+impl Display for Hints {
+    // All the levels, each in a paragraph of its own
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.join("\n\n"))
+    }
+}
+
+// An input fixture piped into the binary of an exercise
+#[derive(Deserialize, Debug)]
+pub struct StdinCase {
+    // Shown in the results, defaults to the number of the case
+    pub name: Option<String>,
+    // The input itself
+    pub input: Option<String>,
+    // Or a file with the input, relative to the workspace root
+    pub input_file: Option<PathBuf>,
+    // What the binary has to print with this input,
+    // instead of the expected output of the exercise
+    #[serde(default)]
+    pub expected_output: Option<ExpectedOutput>,
+}
+
+impl StdinCase {
+    // The input to pipe into the binary, read from its file if it has one
+    pub fn input(&self) -> io::Result<String> {
+        match (&self.input_file, &self.input) {
+            (Some(path), _) => fs::read_to_string(path),
+            (None, input) => Ok(input.clone().unwrap_or_default()),
+        }
+    }
 }
 
 // An enum to track of the state of an Exercise.
@@ -79,23 +319,67 @@ pub struct ContextLine {
 // The result of compiling an exercise
 pub struct CompiledExercise<'a> {
     exercise: &'a Exercise,
+    // The test harnesses cargo built for a cargo exercise
+    executables: Vec<PathBuf>,
     _handle: FileHandle,
 }
 
 impl<'a> CompiledExercise<'a> {
     // Run the compiled exercise
     pub fn run(&self) -> Result<ExerciseOutput, ExerciseOutput> {
-        self.exercise.run()
+        self.exercise.run(&self.executables)
+    }
+
+    // Run the compiled binary with the given input piped into its stdin
+    pub fn run_with_input(&self, input: &str) -> Result<ExerciseOutput, ExerciseOutput> {
+        self.exercise
+            .run_binary(&mut Command::new(temp_file()), Some(input))
     }
 }
 
 // A representation of an already executed binary
-#[derive(Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct ExerciseOutput {
     // The textual contents of the standard output of the binary
     pub stdout: String,
     // The textual contents of the standard error of the binary
     pub stderr: String,
+    // The diagnostics of the compiler if the exercise failed to compile,
+    // stderr then only holds what wasn't a diagnostic
+    #[serde(default)]
+    pub diagnostics: Vec<Diagnostic>,
+    // The limit the binary was stopped for, if any.
+    // The output is then whatever was captured until that point.
+    pub limit_exceeded: Option<Limit>,
+}
+
+// A limit the runner enforces on the binary of an exercise.
+// Memory, CPU time and file sizes are only limited on Linux.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub enum Limit {
+    // The binary ran for longer than the exercise's timeout
+    Timeout(Duration),
+    // The binary used more CPU time than allowed
+    Cpu(Duration),
+    // The binary tried to allocate more memory than allowed, in bytes
+    Memory(u64),
+    // The binary tried to write a file larger than allowed, in bytes
+    FileSize(u64),
+    // The binary printed more to stdout or stderr than allowed, in bytes
+    Output(u64),
+}
+
+impl Display for Limit {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Limit::Timeout(timeout) => write!(f, "the time limit of {}s", timeout.as_secs()),
+            Limit::Cpu(cpu) => write!(f, "the CPU time limit of {}s", cpu.as_secs()),
+            Limit::Memory(bytes) => write!(f, "the memory limit of {} MiB", bytes >> 20),
+            Limit::FileSize(bytes) => write!(f, "the file size limit of {} MiB", bytes >> 20),
+            Limit::Output(bytes) => write!(f, "the output limit of {} MiB", bytes >> 20),
+        }
+    }
 }
 
 struct FileHandle;
@@ -107,149 +391,448 @@ impl Drop for FileHandle {
 }
 
 impl Exercise {
-    pub fn compile(&self) -> Result<CompiledExercise, ExerciseOutput> {
+    pub fn compile(&self) -> Result<CompiledExercise<'_>, ExerciseOutput> {
         let cmd = match self.mode {
             Mode::Compile => Command::new("rustc")
-                .args(&[self.path.to_str().unwrap(), "-o", &temp_file()])
-                .args(RUSTC_COLOR_ARGS)
-                .args(RUSTC_EDITION_ARGS)
+                .args([self.path.to_str().unwrap(), "-o", &temp_file()])
+                .args(RUSTC_JSON_ARGS)
+                .args(self.rustc_args())
                 .output(),
             Mode::Test => Command::new("rustc")
-                .args(&["--test", self.path.to_str().unwrap(), "-o", &temp_file()])
-                .args(RUSTC_COLOR_ARGS)
-                .args(RUSTC_EDITION_ARGS)
+                .args(["--test", self.path.to_str().unwrap(), "-o", &temp_file()])
+                .args(RUSTC_JSON_ARGS)
+                .args(self.rustc_args())
+                .output(),
+            // Only the errors matter, so there is no need to generate code
+            Mode::CompileFail => Command::new("rustc")
+                .args([
+                    self.path.to_str().unwrap(),
+                    "--emit=metadata",
+                    "-o",
+                    &temp_file(),
+                ])
+                .args(RUSTC_JSON_ARGS)
+                .args(self.rustc_args())
                 .output(),
+            // The examples are compiled by rustdoc when running them,
+            // against the library built here, see DOCTEST_COMPILE_SECS
+            Mode::Doctest => Command::new("rustc")
+                .args([self.path.to_str().unwrap(), "--crate-type", "lib"])
+                .args(["--crate-name", &self.crate_name(), "-o", &temp_rlib()])
+                .args(RUSTC_JSON_ARGS)
+                .args(self.rustc_args())
+                .output(),
+            // Clippy exercises are built by cargo from a manifest of their own,
+            // with clippy-driver in place of rustc, so that linting them
+            // also builds the binary. Cargo replays the lints of a build
+            // that is still fresh, so nothing has to be cleaned first.
             Mode::Clippy => {
-                let cargo_toml = format!(
-                    r#"[package]
-name = "{}"
-version = "0.0.1"
-edition = "2021"
-[[bin]]
-name = "{}"
-path = "{}.rs""#,
-                    self.name, self.name, self.name
-                );
-                let cargo_toml_error_msg = if env::var("NO_EMOJI").is_ok() {
-                    "Failed to write Clippy Cargo.toml file."
-                } else {
-                    "Failed to write 📎 Clippy 📎 Cargo.toml file."
-                };
-                fs::write(CLIPPY_CARGO_TOML_PATH, cargo_toml).expect(cargo_toml_error_msg);
-                // To support the ability to run the clippy exercises, build
-                // an executable, in addition to running clippy. With a
-                // compilation failure, this would silently fail. But we expect
-                // clippy to reflect the same failure while compiling later.
-                Command::new("rustc")
-                    .args(&[self.path.to_str().unwrap(), "-o", &temp_file()])
-                    .args(RUSTC_COLOR_ARGS)
-                    .args(RUSTC_EDITION_ARGS)
-                    .output()
-                    .expect("Failed to compile!");
-                // Due to an issue with Clippy, a cargo clean is required to catch all lints.
-                // See https://github.com/rust-lang/rust-clippy/issues/2604
-                // This is already fixed on Clippy's master branch. See this issue to track merging into Cargo:
-                // https://github.com/rust-lang/rust-clippy/issues/3837
+                let dir = Path::new(CARGO_TARGET_DIR).join("clippy").join(&self.name);
+                let manifest = self.clippy_manifest(&dir).unwrap_or_else(|e| {
+                    let msg = if env::var("NO_EMOJI").is_ok() {
+                        "Failed to write Clippy Cargo.toml file"
+                    } else {
+                        "Failed to write 📎 Clippy 📎 Cargo.toml file"
+                    };
+                    panic!("{msg}: {e}")
+                });
                 Command::new("cargo")
-                    .args(&["clean", "--manifest-path", CLIPPY_CARGO_TOML_PATH])
+                    .args(["rustc", "--offline", "--manifest-path"])
+                    .arg(manifest)
+                    .arg("--target-dir")
+                    .arg(dir.join("target"))
+                    .env("RUSTC_WORKSPACE_WRAPPER", "clippy-driver")
                     .args(RUSTC_COLOR_ARGS)
-                    .output()
-                    .expect("Failed to run 'cargo clean'");
-                Command::new("cargo")
-                    .args(&["clippy", "--manifest-path", CLIPPY_CARGO_TOML_PATH])
-                    .args(RUSTC_COLOR_ARGS)
-                    .args(&["--", "-D", "warnings", "-D", "clippy::float_cmp"])
+                    .args(CARGO_JSON_ARGS)
+                    .arg("--")
+                    .args(self.lints().args())
+                    .args(self.extra_rustc_args())
                     .output()
             }
+            // Building the test harnesses also builds everything they need,
+            // like the library, binaries and build script of the crate.
+            // Doctests have no harness to build, so they aren't run.
+            Mode::Cargo => Command::new("cargo")
+                .args(["test", "--no-run", "--offline", "--manifest-path"])
+                .arg(self.path.join("Cargo.toml"))
+                .arg("--target-dir")
+                .arg(Path::new(CARGO_TARGET_DIR).join("cargo").join(&self.name))
+                .args(RUSTC_COLOR_ARGS)
+                .args(CARGO_JSON_ARGS)
+                .output(),
         }
         .expect("Failed to run 'compile' command.");
 
         if cmd.status.success() {
             Ok(CompiledExercise {
                 exercise: self,
+                executables: executables(&String::from_utf8_lossy(&cmd.stdout), self.mode),
                 _handle: FileHandle,
             })
         } else {
             clean();
+            let stderr = String::from_utf8_lossy(&cmd.stderr).to_string();
+            // Diagnostics passed through cargo come on stdout, rustc's on stderr
+            let (diagnostics, stderr) = match self.mode {
+                Mode::Clippy | Mode::Cargo => (
+                    diagnostics::parse_cargo(&String::from_utf8_lossy(&cmd.stdout)),
+                    stderr,
+                ),
+                _ => diagnostics::parse_rustc(&stderr),
+            };
             Err(ExerciseOutput {
-                stdout: String::from_utf8_lossy(&cmd.stdout).to_string(),
-                stderr: String::from_utf8_lossy(&cmd.stderr).to_string(),
+                stdout: String::new(),
+                stderr,
+                diagnostics,
+                limit_exceeded: None,
             })
         }
     }
 
-    fn run(&self) -> Result<ExerciseOutput, ExerciseOutput> {
-        let arg = match self.mode {
-            Mode::Test => "--show-output",
-            _ => "",
+    fn run(&self, executables: &[PathBuf]) -> Result<ExerciseOutput, ExerciseOutput> {
+        match self.mode {
+            Mode::Test => self.run_binary(Command::new(temp_file()).arg("--show-output"), None),
+            Mode::Doctest => self.run_binary_within(
+                Command::new("rustdoc")
+                    .args(["--test", self.path.to_str().unwrap(), "--crate-type", "lib"])
+                    .args(["--crate-name", &self.crate_name(), "--extern"])
+                    .arg(format!("{}={}", self.crate_name(), temp_rlib()))
+                    .args(self.rustc_args())
+                    .args(RUSTC_COLOR_ARGS),
+                None,
+                self.timeout() + Duration::from_secs(DOCTEST_COMPILE_SECS),
+            ),
+            // Run the test harnesses one after the other from the crate's
+            // directory like `cargo test` does, stopping at the first failure
+            Mode::Cargo => {
+                let mut output = ExerciseOutput {
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    diagnostics: Vec::new(),
+                    limit_exceeded: None,
+                };
+                for executable in executables {
+                    let mut cmd = Command::new(executable);
+                    cmd.arg("--show-output").current_dir(&self.path);
+                    let result = self.run_binary(&mut cmd, None);
+                    let (Ok(harness) | Err(harness)) = &result;
+                    output.stdout.push_str(&harness.stdout);
+                    output.stderr.push_str(&harness.stderr);
+                    if result.is_err() {
+                        output.limit_exceeded = harness.limit_exceeded;
+                        return Err(output);
+                    }
+                }
+                Ok(output)
+            }
+            Mode::Clippy => {
+                let binary = executables
+                    .first()
+                    .expect("Cargo didn't build the binary of the clippy exercise");
+                self.run_binary(&mut Command::new(binary), None)
+            }
+            _ => self.run_binary(&mut Command::new(temp_file()), None),
+        }
+    }
+
+    // Write the manifest clippy exercises are built from into the given directory,
+    // unless it's already there. Returns the path of the manifest.
+    fn clippy_manifest(&self, dir: &Path) -> io::Result<PathBuf> {
+        let path = env::current_dir()?.join(&self.path);
+        let cargo_toml = format!(
+            r#"[package]
+name = "{}"
+version = "0.0.1"
+edition = "{}"
+
+[[bin]]
+name = "{}"
+path = {}
+
+# Keeps the manifest out of any workspace it's in
+[workspace]
+"#,
+            self.name,
+            self.edition(),
+            self.name,
+            toml::Value::String(path.to_string_lossy().into_owned())
+        );
+        let manifest = dir.join("Cargo.toml");
+        if fs::read_to_string(&manifest).ok().as_deref() != Some(cargo_toml.as_str()) {
+            fs::create_dir_all(dir)?;
+            fs::write(&manifest, cargo_toml)?;
+        }
+        Ok(manifest)
+    }
+
+    // Run a binary of this exercise within its limits,
+    // with the given input or else an empty stdin
+    fn run_binary(
+        &self,
+        cmd: &mut Command,
+        input: Option<&str>,
+    ) -> Result<ExerciseOutput, ExerciseOutput> {
+        self.run_binary_within(cmd, input, self.timeout())
+    }
+
+    // Run a binary of this exercise within its limits,
+    // except for the time it may take which is given
+    fn run_binary_within(
+        &self,
+        cmd: &mut Command,
+        input: Option<&str>,
+        timeout: Duration,
+    ) -> Result<ExerciseOutput, ExerciseOutput> {
+        let stdin = match input {
+            Some(_) => Stdio::piped(),
+            None => Stdio::null(),
         };
-        let cmd = Command::new(&temp_file())
-            .arg(arg)
-            .output()
-            .expect("Failed to run 'run' command");
+        cmd.stdin(stdin)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        // Put the binary into its own process group,
+        // so that it can be killed along with everything it spawned
+        #[cfg(unix)]
+        std::os::unix::process::CommandExt::process_group(cmd, 0);
+        // The CPU time limit is a bit above the timeout, so that it only
+        // stops binaries that keep several threads busy at once
+        let cpu_limit = timeout + Duration::from_secs(1);
+        #[cfg(target_os = "linux")]
+        unsafe {
+            std::os::unix::process::CommandExt::pre_exec(cmd, move || {
+                set_resource_limits(cpu_limit)
+            });
+        }
+        let mut child = cmd.spawn().expect("Failed to run 'run' command");
+        session::track_process_group(child.id());
 
-        let output = ExerciseOutput {
-            stdout: String::from_utf8_lossy(&cmd.stdout).to_string(),
-            stderr: String::from_utf8_lossy(&cmd.stderr).to_string(),
+        // Write the input on a separate thread, since the binary may not read
+        // all of it, or only after it filled up its output pipes.
+        // Dropping stdin afterwards lets the binary see the end of the input.
+        if let (Some(mut stdin), Some(input)) = (child.stdin.take(), input) {
+            let input = input.to_owned();
+            thread::spawn(move || {
+                let _ = stdin.write_all(input.as_bytes());
+            });
+        }
+
+        let output_exceeded = Arc::new(AtomicBool::new(false));
+        let stdout = read_in_background(child.stdout.take(), Arc::clone(&output_exceeded));
+        let stderr = read_in_background(child.stderr.take(), Arc::clone(&output_exceeded));
+
+        let deadline = Instant::now() + timeout;
+        let mut status = loop {
+            match child.try_wait().expect("Failed to wait for 'run' command") {
+                Some(status) => break Some(status),
+                None if output_exceeded.load(Ordering::SeqCst) || Instant::now() >= deadline => {
+                    break None;
+                }
+                None => thread::sleep(Duration::from_millis(10)),
+            }
         };
+        // Whatever the binary left running would keep its pipes open
+        kill_process_group(&mut child);
+        session::untrack_process_group(child.id());
 
-        if cmd.status.success() {
-            Ok(output)
+        // Processes that left the group can still hold the pipes open,
+        // they are only waited for until the deadline. When the binary was
+        // killed at the deadline, what it wrote last still gets read.
+        let deadline =
+            deadline.max(Instant::now() + Duration::from_millis(KILLED_OUTPUT_GRACE_MILLIS));
+        let (stdout, stdout_closed) = stdout.finish(deadline);
+        let (stderr, stderr_closed) = stderr.finish(deadline);
+        if !stdout_closed || !stderr_closed {
+            status = None;
+        }
+        let stdout = String::from_utf8_lossy(&stdout).to_string();
+        let stderr = String::from_utf8_lossy(&stderr).to_string();
+        let limit_exceeded = if output_exceeded.load(Ordering::SeqCst) {
+            Some(Limit::Output(OUTPUT_LIMIT_BYTES))
         } else {
-            Err(output)
+            match status {
+                Some(status) => resource_limit_exceeded(status, &stderr, cpu_limit),
+                None => Some(Limit::Timeout(timeout)),
+            }
+        };
+        let output = ExerciseOutput {
+            stdout,
+            stderr,
+            diagnostics: Vec::new(),
+            limit_exceeded,
+        };
+
+        match status {
+            Some(status) if status.success() && output.limit_exceeded.is_none() => Ok(output),
+            _ => Err(output),
+        }
+    }
+
+    pub fn edition(&self) -> &str {
+        self.edition.as_deref().unwrap_or(DEFAULT_EDITION)
+    }
+
+    // The arguments rustc and rustdoc compile the exercise with
+    fn rustc_args(&self) -> Vec<String> {
+        let mut args = vec!["--edition".to_string(), self.edition().to_string()];
+        args.extend(self.extra_rustc_args());
+        args
+    }
+
+    // The configuration options and extra arguments from info.toml,
+    // which clippy exercises get passed on through cargo
+    fn extra_rustc_args(&self) -> Vec<String> {
+        let cfg = self.cfg.iter().flatten();
+        let mut args: Vec<String> = cfg
+            .flat_map(|cfg| ["--cfg".to_string(), cfg.clone()])
+            .collect();
+        args.extend(self.rustc_args.iter().flatten().cloned());
+        args
+    }
+
+    // The lints clippy checks a clippy exercise with
+    pub fn lints(&self) -> Lints {
+        self.lints.clone().unwrap_or_else(Lints::builtin)
+    }
+
+    // The name of the crate of a doctest exercise, which is how its examples refer to it
+    fn crate_name(&self) -> String {
+        self.name.replace('-', "_")
+    }
+
+    // How the given stdout of the binary differs from the expected output,
+    // if the exercise or the given stdin fixture has one and it doesn't match
+    pub fn output_diff(&self, case: Option<&StdinCase>, stdout: &str) -> Option<String> {
+        let expected = case
+            .and_then(|case| case.expected_output.as_ref())
+            .or(self.expected_output.as_ref());
+        match (self.mode, expected) {
+            (Mode::Compile, Some(expected)) => expected.diff(stdout),
+            _ => None,
         }
     }
 
+    // How long the compiled binary of this exercise may run
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS))
+    }
+
     pub fn state(&self) -> State {
-        let mut source_file =
-            File::open(&self.path).expect("We were unable to open the exercise file!");
-
-        let source = {
-            let mut s = String::new();
-            source_file
-                .read_to_string(&mut s)
-                .expect("We were unable to read the exercise file!");
-            s
-        };
+        match self.marker() {
+            Some((_, context)) => State::Pending(context),
+            None => State::Done,
+        }
+    }
 
+    // The file that still has the "I AM NOT DONE" marker, if any.
+    // Only cargo exercises can have it in another file than their path.
+    pub fn pending_file(&self) -> Option<PathBuf> {
+        self.marker().map(|(file, _)| file)
+    }
+
+    // Find the first "I AM NOT DONE" marker in the files of the exercise,
+    // along with the lines around it
+    fn marker(&self) -> Option<(PathBuf, Vec<ContextLine>)> {
+        let files = self
+            .source_files()
+            .expect("We were unable to open the exercise file!");
         let re = Regex::new(I_AM_DONE_REGEX).unwrap();
 
-        if !re.is_match(&source) {
-            return State::Done;
-        }
+        for file in files {
+            let source = match fs::read_to_string(&file) {
+                Ok(source) => source,
+                // Crates can contain binary files, which can't have a marker
+                Err(e) if e.kind() == io::ErrorKind::InvalidData => continue,
+                Err(_) => panic!("We were unable to read the exercise file!"),
+            };
 
-        let matched_line_index = source
-            .lines()
-            .enumerate()
-            .find_map(|(i, line)| if re.is_match(line) { Some(i) } else { None })
-            .expect("This should not happen at all");
-
-        let min_line = ((matched_line_index as i32) - (CONTEXT as i32)).max(0) as usize;
-        let max_line = matched_line_index + CONTEXT;
-
-        let context = source
-            .lines()
-            .enumerate()
-            .filter(|&(i, _)| i >= min_line && i <= max_line)
-            .map(|(i, line)| ContextLine {
-                line: line.to_string(),
-                number: i + 1,
-                important: i == matched_line_index,
-            })
-            .collect();
+            let Some(matched) = re.find(&source) else {
+                continue;
+            };
+
+            // The marker can span lines, like "//\n I AM NOT DONE",
+            // the line with its end is the one shown as important
+            let matched_line_index = source[..matched.end()].matches('\n').count();
+
+            let min_line = ((matched_line_index as i32) - (CONTEXT as i32)).max(0) as usize;
+            let max_line = matched_line_index + CONTEXT;
+
+            let context = source
+                .lines()
+                .enumerate()
+                .filter(|&(i, _)| i >= min_line && i <= max_line)
+                .map(|(i, line)| ContextLine {
+                    line: line.to_string(),
+                    number: i + 1,
+                    important: i == matched_line_index,
+                })
+                .collect();
 
-        State::Pending(context)
+            return Some((file, context));
+        }
+        None
     }
 
     // Check that the exercise looks to be solved using self.state()
-    // This is not the best way to check since
-    // the user can just remove the "I AM NOT DONE" string from the file
-    // without actually having solved anything.
-    // The only other way to truly check this would to compile and run
-    // the exercise; which would be both costly and counterintuitive
+    // This only checks that the "I AM NOT DONE" string has been removed,
+    // use Progress::is_done to also check that the exercise was verified.
     pub fn looks_done(&self) -> bool {
         self.state() == State::Done
     }
+
+    // A hex encoded hash of the exercise's source, used to tell whether
+    // the file has changed since it was last verified.
+    // For cargo exercises, it covers the paths and contents of all their files.
+    pub fn content_hash(&self) -> io::Result<String> {
+        let mut hasher = Sha256::new();
+        for file in self.source_files()? {
+            if let Mode::Cargo = self.mode {
+                let relative = file.strip_prefix(&self.path).unwrap_or(&file);
+                hasher.update(relative.to_string_lossy().as_bytes());
+                hasher.update([0]);
+            }
+            hasher.update(fs::read(&file)?);
+        }
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+
+    // The files the exercise is made of, which for a cargo exercise
+    // are all the files of its crate apart from build artifacts
+    pub fn source_files(&self) -> io::Result<Vec<PathBuf>> {
+        match self.mode {
+            Mode::Cargo => crate_files(&self.path),
+            _ => Ok(vec![self.path.clone()]),
+        }
+    }
+
+    // Where the reference solution is, if the exercise can have one.
+    // Whether there is one is up to `solution::files`.
+    pub fn solution_path(&self) -> Option<PathBuf> {
+        if let Some(path) = &self.solution {
+            return Some(path.clone());
+        }
+        let path = self.path.strip_prefix(".").unwrap_or(&self.path);
+        Some(Path::new(SOLUTIONS_DIR).join(path.strip_prefix("exercises").ok()?))
+    }
+
+    // The section of an exercise is the directory it is in, like `intro`
+    pub fn section(&self) -> String {
+        self.path
+            .parent()
+            .and_then(Path::file_name)
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "exercises".to_string())
+    }
+
+    // Whether the given canonicalized file is part of this exercise
+    pub fn owns(&self, file: &Path) -> bool {
+        match self.mode {
+            Mode::Cargo => self
+                .path
+                .canonicalize()
+                .is_ok_and(|dir| file.starts_with(dir)),
+            _ => file.ends_with(&self.path),
+        }
+    }
 }
 
 impl Display for Exercise {
@@ -260,23 +843,220 @@ impl Display for Exercise {
 
 #[inline]
 fn clean() {
-    let _ignored = remove_file(&temp_file());
+    let _ignored = remove_file(temp_file());
+    let _ignored = remove_file(temp_rlib());
+}
+
+// The files of a crate in a stable order,
+// skipping hidden files and what cargo generates
+pub fn crate_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    collect_crate_files(dir, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn collect_crate_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if name == "target" || name == "Cargo.lock" || name.to_string_lossy().starts_with('.') {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            collect_crate_files(&entry.path(), files)?;
+        } else {
+            files.push(entry.path());
+        }
+    }
+    Ok(())
+}
+
+// The executables cargo built, from its JSON messages on stdout.
+// These are the test harnesses of cargo exercises, or else the binary.
+fn executables(stdout: &str, mode: Mode) -> Vec<PathBuf> {
+    #[derive(Deserialize)]
+    struct Artifact {
+        reason: String,
+        profile: Option<Profile>,
+        executable: Option<PathBuf>,
+    }
+    #[derive(Deserialize)]
+    struct Profile {
+        test: bool,
+    }
+
+    stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Artifact>(line).ok())
+        .filter(|artifact| artifact.reason == "compiler-artifact")
+        .filter(|artifact| {
+            let test = artifact.profile.as_ref().is_some_and(|p| p.test);
+            test == matches!(mode, Mode::Cargo)
+        })
+        .filter_map(|artifact| artifact.executable)
+        .collect()
+}
+
+// Collect what is written to a pipe of a child process on a separate thread,
+// so that neither pipe can fill up and block the child.
+// Only the first OUTPUT_LIMIT_BYTES are kept, anything above that
+// sets the `exceeded` flag and is thrown away.
+fn read_in_background(
+    pipe: Option<impl Read + Send + 'static>,
+    exceeded: Arc<AtomicBool>,
+) -> Reader {
+    let buf = Arc::new(Mutex::new(Vec::new()));
+    let shared = Arc::clone(&buf);
+    let handle = thread::spawn(move || {
+        let Some(mut pipe) = pipe else {
+            return;
+        };
+        let mut chunk = [0; 8192];
+        loop {
+            let read = match pipe.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(_) => break,
+            };
+            // Past the limit, the rest is read only to not block the binary
+            let mut buf = shared.lock().unwrap();
+            let room = OUTPUT_LIMIT_BYTES as usize - buf.len();
+            buf.extend_from_slice(&chunk[..read.min(room)]);
+            if read > room {
+                exceeded.store(true, Ordering::SeqCst);
+            }
+        }
+    });
+    Reader { buf, handle }
+}
+
+// The output of a pipe being read on another thread
+struct Reader {
+    buf: Arc<Mutex<Vec<u8>>>,
+    handle: JoinHandle<()>,
+}
+
+impl Reader {
+    // What was read until the pipe was closed or the deadline passed,
+    // and whether the pipe was closed in time
+    fn finish(self, deadline: Instant) -> (Vec<u8>, bool) {
+        while !self.handle.is_finished() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        let closed = self.handle.is_finished();
+        if closed {
+            let _ = self.handle.join();
+        }
+        let buf = std::mem::take(&mut *self.buf.lock().unwrap());
+        (buf, closed)
+    }
+}
+
+// Limit the memory, CPU time and file sizes available to the current process.
+// This runs in the forked child right before the exercise binary is executed,
+// so it must not allocate.
+#[cfg(target_os = "linux")]
+fn set_resource_limits(cpu_limit: Duration) -> io::Result<()> {
+    let cpu_secs = cpu_limit.as_secs();
+    let limits = [
+        (libc::RLIMIT_AS, MEMORY_LIMIT_BYTES, MEMORY_LIMIT_BYTES),
+        // Going over the soft limit sends SIGXCPU, the hard limit SIGKILL
+        (libc::RLIMIT_CPU, cpu_secs, cpu_secs + 1),
+        (
+            libc::RLIMIT_FSIZE,
+            FILE_SIZE_LIMIT_BYTES,
+            FILE_SIZE_LIMIT_BYTES,
+        ),
+    ];
+    for (resource, soft, hard) in limits {
+        let mut limit = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        // Limits can only be lowered, never raised above the current hard limit
+        if unsafe { libc::getrlimit(resource, &mut limit) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let max = limit.rlim_max;
+        limit.rlim_cur = (soft as libc::rlim_t).min(max);
+        limit.rlim_max = (hard as libc::rlim_t).min(max);
+        if unsafe { libc::setrlimit(resource, &limit) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+// Tell which resource limit made the binary terminate, if any
+#[cfg(target_os = "linux")]
+fn resource_limit_exceeded(status: ExitStatus, stderr: &str, cpu_limit: Duration) -> Option<Limit> {
+    use std::os::unix::process::ExitStatusExt;
+    match status.signal()? {
+        libc::SIGXCPU => Some(Limit::Cpu(cpu_limit)),
+        libc::SIGXFSZ => Some(Limit::FileSize(FILE_SIZE_LIMIT_BYTES)),
+        // Rust aborts the process when an allocation fails
+        libc::SIGABRT if stderr.contains("memory allocation of") => {
+            Some(Limit::Memory(MEMORY_LIMIT_BYTES))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn resource_limit_exceeded(
+    _status: ExitStatus,
+    _stderr: &str,
+    _cpu_limit: Duration,
+) -> Option<Limit> {
+    None
+}
+
+// Kill a child process started in its own process group,
+// together with any processes it spawned itself
+fn kill_process_group(child: &mut Child) {
+    #[cfg(unix)]
+    unsafe {
+        libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL);
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+// An exercise for the tests, with nothing set but what every exercise has
+#[cfg(test)]
+pub(crate) fn test_exercise(name: &str, path: impl Into<PathBuf>, mode: Mode) -> Exercise {
+    Exercise {
+        name: name.into(),
+        path: path.into(),
+        mode,
+        hint: Hints::default(),
+        timeout_secs: None,
+        expected_output: None,
+        stdin: Vec::new(),
+        expected_errors: Vec::new(),
+        lints: None,
+        edition: None,
+        rustc_args: None,
+        cfg: None,
+        solution: None,
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use std::path::Path;
+    use std::fs::File;
 
     #[test]
     fn test_clean() {
-        File::create(&temp_file()).unwrap();
-        let exercise = Exercise {
-            name: String::from("example"),
-            path: PathBuf::from("tests/fixture/state/pending_exercise.rs"),
-            mode: Mode::Compile,
-            hint: String::from(""),
-        };
+        File::create(temp_file()).unwrap();
+        let exercise = test_exercise(
+            "example",
+            "tests/fixture/state/pending_exercise.rs",
+            Mode::Compile,
+        );
         let compiled = exercise.compile().unwrap();
         drop(compiled);
         assert!(!Path::new(&temp_file()).exists());
@@ -284,12 +1064,11 @@ mod test {
 
     #[test]
     fn test_pending_state() {
-        let exercise = Exercise {
-            name: "pending_exercise".into(),
-            path: PathBuf::from("tests/fixture/state/pending_exercise.rs"),
-            mode: Mode::Compile,
-            hint: String::new(),
-        };
+        let exercise = test_exercise(
+            "pending_exercise",
+            "tests/fixture/state/pending_exercise.rs",
+            Mode::Compile,
+        );
 
         let state = exercise.state();
         let expected = vec![
@@ -324,26 +1103,144 @@ mod test {
     }
 
     #[test]
-    fn test_finished_exercise() {
-        let exercise = Exercise {
-            name: "finished_exercise".into(),
-            path: PathBuf::from("tests/fixture/state/finished_exercise.rs"),
-            mode: Mode::Compile,
-            hint: String::new(),
+    fn test_marker_spanning_lines() {
+        let path = env::temp_dir().join(format!("rustlings_marker_{}.rs", std::process::id()));
+        fs::write(&path, "fn main() {}\n//\n   I AM NOT DONE\n").unwrap();
+        let exercise = test_exercise("split_marker", path.clone(), Mode::Compile);
+
+        let state = exercise.state();
+        fs::remove_file(&path).unwrap();
+        let State::Pending(context) = state else {
+            panic!("The exercise should be pending");
         };
+        let important: Vec<usize> = context
+            .iter()
+            .filter(|line| line.important)
+            .map(|line| line.number)
+            .collect();
+        assert_eq!(important, [3]);
+    }
+
+    #[test]
+    fn test_pending_cargo_exercise() {
+        let exercise = test_exercise(
+            "pending_cargo_exercise",
+            "tests/fixture/state/pending_cargo_exercise",
+            Mode::Cargo,
+        );
+
+        assert!(!exercise.looks_done());
+        assert_eq!(
+            exercise.pending_file(),
+            Some(PathBuf::from(
+                "tests/fixture/state/pending_cargo_exercise/src/nested.rs"
+            ))
+        );
+        assert!(exercise
+            .source_files()
+            .unwrap()
+            .iter()
+            .all(|file| !file.starts_with("tests/fixture/state/pending_cargo_exercise/target")));
+        assert!(exercise.owns(
+            &Path::new("tests/fixture/state/pending_cargo_exercise/src/main.rs")
+                .canonicalize()
+                .unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_finished_exercise() {
+        let exercise = test_exercise(
+            "finished_exercise",
+            "tests/fixture/state/finished_exercise.rs",
+            Mode::Compile,
+        );
 
         assert_eq!(exercise.state(), State::Done);
     }
 
     #[test]
     fn test_exercise_with_output() {
-        let exercise = Exercise {
-            name: "exercise_with_output".into(),
-            path: PathBuf::from("tests/fixture/success/testSuccess.rs"),
-            mode: Mode::Test,
-            hint: String::new(),
-        };
+        let exercise = test_exercise(
+            "exercise_with_output",
+            "tests/fixture/success/testSuccess.rs",
+            Mode::Test,
+        );
         let out = exercise.compile().unwrap().run().unwrap();
         assert!(out.stdout.contains("THIS TEST TOO SHALL PASS"));
     }
+
+    #[test]
+    fn test_default_lints() {
+        let list: ExerciseList = toml::from_str(
+            r#"
+            [defaults]
+            lints = { deny = ["clippy::pedantic"], allow = ["clippy::must_use_candidate"] }
+
+            [[exercises]]
+            name = "clippy1"
+            path = "exercises/clippy/clippy1.rs"
+            mode = "clippy"
+            hint = ""
+
+            [[exercises]]
+            name = "clippy2"
+            path = "exercises/clippy/clippy2.rs"
+            mode = "clippy"
+            lints = { warn = ["clippy::unwrap_used"] }
+            hint = ""
+            "#,
+        )
+        .unwrap();
+        let exercises = list.into_exercises();
+        assert_eq!(
+            exercises[0].lints().args(),
+            ["-D", "clippy::pedantic", "-A", "clippy::must_use_candidate"]
+        );
+        assert_eq!(
+            exercises[0].lints().to_string(),
+            "deny clippy::pedantic; allow clippy::must_use_candidate"
+        );
+        assert_eq!(exercises[1].lints().args(), ["-W", "clippy::unwrap_used"]);
+        assert_eq!(
+            Lints::builtin().args(),
+            ["-D", "warnings", "-D", "clippy::float_cmp"]
+        );
+    }
+
+    #[test]
+    fn test_default_rustc_args() {
+        let list: ExerciseList = toml::from_str(
+            r#"
+            [defaults]
+            edition = "2024"
+            cfg = ["lesson"]
+
+            [[exercises]]
+            name = "flags1"
+            path = "exercises/flags/flags1.rs"
+            mode = "compile"
+            hint = ""
+
+            [[exercises]]
+            name = "flags2"
+            path = "exercises/flags/flags2.rs"
+            mode = "compile"
+            edition = "2015"
+            cfg = []
+            rustc_args = ["-C", "overflow-checks=off"]
+            hint = ""
+            "#,
+        )
+        .unwrap();
+        let exercises = list.into_exercises();
+        assert_eq!(
+            exercises[0].rustc_args(),
+            ["--edition", "2024", "--cfg", "lesson"]
+        );
+        assert_eq!(
+            exercises[1].rustc_args(),
+            ["--edition", "2015", "-C", "overflow-checks=off"]
+        );
+    }
 }
@@ -0,0 +1,165 @@
+use crate::diff;
+use console::style;
+use regex::Regex;
+use serde::Deserialize;
+use std::fs;
+use std::path::PathBuf;
+
+/// What the binary of an exercise has to print on stdout.
+///
+/// In `info.toml`, this is either the expected text itself or a table like
+/// `{ file = "exercises/intro/intro2.out", trim = false, regex = true }`.
+#[derive(Deserialize, Debug)]
+#[serde(from = "RawExpectedOutput")]
+pub struct ExpectedOutput {
+    pub source: Source,
+    /// Ignore whitespace at the end of lines and blank lines
+    /// at the start and end of the output, on by default
+    pub trim: bool,
+    /// Treat every expected line as a regex the whole line has to match
+    pub regex: bool,
+}
+
+#[derive(Debug)]
+pub enum Source {
+    Text(String),
+    /// A file with the expected text, relative to the workspace root
+    File(PathBuf),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawExpectedOutput {
+    Text(String),
+    Table {
+        text: Option<String>,
+        file: Option<PathBuf>,
+        #[serde(default = "default_trim")]
+        trim: bool,
+        #[serde(default)]
+        regex: bool,
+    },
+}
+
+fn default_trim() -> bool {
+    true
+}
+
+impl From<RawExpectedOutput> for ExpectedOutput {
+    fn from(raw: RawExpectedOutput) -> Self {
+        match raw {
+            RawExpectedOutput::Text(text) => ExpectedOutput {
+                source: Source::Text(text),
+                trim: true,
+                regex: false,
+            },
+            RawExpectedOutput::Table {
+                text,
+                file,
+                trim,
+                regex,
+            } => ExpectedOutput {
+                // A file takes precedence, a table with neither expects no output
+                source: match (file, text) {
+                    (Some(file), _) => Source::File(file),
+                    (None, text) => Source::Text(text.unwrap_or_default()),
+                },
+                trim,
+                regex,
+            },
+        }
+    }
+}
+
+impl ExpectedOutput {
+    /// The expected text, read from its file if it has one
+    pub fn text(&self) -> Result<String, String> {
+        match &self.source {
+            Source::Text(text) => Ok(text.clone()),
+            Source::File(path) => fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read the expected output {}: {e}", path.display())),
+        }
+    }
+
+    /// Compare the stdout of a binary with the expected output.
+    /// Returns `None` if it matches, or else a coloured line diff.
+    pub fn diff(&self, stdout: &str) -> Option<String> {
+        let expected = match self.text() {
+            Ok(expected) => expected,
+            Err(e) => return Some(format!("{}\n", style(e).red())),
+        };
+        let expected = self.lines(&expected);
+        let actual = self.lines(stdout);
+
+        // With regexes, expected lines that match are replaced by the
+        // actual line, so that only the lines that don't match show up
+        let expected: Vec<&str> = if self.regex {
+            expected
+                .iter()
+                .enumerate()
+                .map(|(i, line)| match actual.get(i) {
+                    Some(actual) if line_matches(line, actual) => *actual,
+                    _ => *line,
+                })
+                .collect()
+        } else {
+            expected
+        };
+
+        if expected == actual {
+            None
+        } else {
+            Some(diff::render(
+                ("expected", "actual"),
+                &diff::lines(&expected, &actual),
+            ))
+        }
+    }
+
+    fn lines<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        let mut lines: Vec<&str> = text.lines().collect();
+        if self.trim {
+            lines = lines.into_iter().map(str::trim_end).collect();
+            while lines.last() == Some(&"") {
+                lines.pop();
+            }
+            let blank = lines.iter().take_while(|line| line.is_empty()).count();
+            lines.drain(..blank);
+        }
+        lines
+    }
+}
+
+fn line_matches(pattern: &str, line: &str) -> bool {
+    Regex::new(&format!("^(?:{pattern})$")).is_ok_and(|re| re.is_match(line))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn expected(toml: &str) -> ExpectedOutput {
+        #[derive(Deserialize)]
+        struct Exercise {
+            expected_output: ExpectedOutput,
+        }
+        toml::from_str::<Exercise>(toml).unwrap().expected_output
+    }
+
+    #[test]
+    fn test_trimmed_text() {
+        let output = expected(r#"expected_output = "Hello world!""#);
+        assert_eq!(output.diff("\nHello world!  \n\n"), None);
+        assert!(output.diff("Hello World!\n").is_some());
+    }
+
+    #[test]
+    fn test_regex_lines() {
+        let output = expected(
+            r#"expected_output = { text = "Took \\d+ms\nDone", trim = false, regex = true }"#,
+        );
+        assert_eq!(output.diff("Took 12ms\nDone\n"), None);
+        let diff = output.diff("Took 12ms\nNot done\n").unwrap();
+        assert!(console::strip_ansi_codes(&diff).contains("  Took 12ms\n- Done\n+ Not done\n"));
+    }
+}
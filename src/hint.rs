@@ -0,0 +1,94 @@
+use crate::exercise::Exercise;
+use crate::progress::Progress;
+use console::style;
+
+/// Which hints of an exercise to show
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Level {
+    /// The level after the ones revealed so far, or the last one
+    Next,
+    /// Every level, which reveals all of them
+    All,
+    /// A single level, starting at 1
+    Exactly(usize),
+}
+
+/// Print hints of the exercise and remember how far they have been revealed.
+/// Fails with a message if the exercise has no such level.
+pub fn show(exercise: &Exercise, progress: &mut Progress, level: Level) -> Result<(), String> {
+    let hints = exercise.hint.levels();
+    if hints.is_empty() {
+        return Ok(());
+    }
+    let levels = match level {
+        Level::Next => {
+            let next = (progress.hints_revealed(exercise) + 1).min(hints.len());
+            next..=next
+        }
+        Level::All => 1..=hints.len(),
+        Level::Exactly(level) if (1..=hints.len()).contains(&level) => level..=level,
+        Level::Exactly(level) => {
+            return Err(format!(
+                "{} has no hint level {level}, it has {} of them",
+                exercise.name,
+                hints.len()
+            ))
+        }
+    };
+
+    // A single hint is printed as is, like it always was
+    if let [hint] = hints {
+        println!("{hint}");
+    } else {
+        for level in levels.clone() {
+            if level > *levels.start() {
+                println!();
+            }
+            let header = format!("Hint {level} of {}:", hints.len());
+            println!("{}", style(header).bold());
+            println!("{}", hints[level - 1]);
+        }
+        if level == Level::Next && *levels.end() < hints.len() {
+            println!();
+            println!("Still stuck? Ask for the next hint to get more help.");
+        }
+    }
+    progress.reveal_hints(exercise, *levels.end());
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::exercise::ExerciseList;
+    use std::fs;
+
+    #[test]
+    fn test_reveal_levels() {
+        let exercise = toml::from_str::<ExerciseList>(
+            r#"
+            [[exercises]]
+            name = "hints1"
+            path = "exercises/hints/hints1.rs"
+            mode = "compile"
+            hint = ["First", "Second", "Third"]
+            "#,
+        )
+        .unwrap()
+        .into_exercises()
+        .remove(0);
+        let path = std::env::temp_dir().join(format!("rustlings_hints_{}", std::process::id()));
+        let mut progress = Progress::load_from(&path);
+
+        show(&exercise, &mut progress, Level::Next).unwrap();
+        assert_eq!(progress.hints_revealed(&exercise), 1);
+        show(&exercise, &mut progress, Level::Exactly(3)).unwrap();
+        show(&exercise, &mut progress, Level::Exactly(2)).unwrap();
+        assert_eq!(progress.hints_revealed(&exercise), 3);
+        assert!(show(&exercise, &mut progress, Level::Exactly(4)).is_err());
+
+        let reloaded = Progress::load_from(&path);
+        assert_eq!(reloaded.hints_revealed(&exercise), 3);
+        fs::remove_file(&path).unwrap();
+    }
+}
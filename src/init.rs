@@ -0,0 +1,53 @@
+use crate::exercise;
+use crate::pristine;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+// What a workspace keeps out of git: build output, the backups of `reset`,
+// the progress and the rust-analyzer project generated by `lsp`
+const GITIGNORE: &str = "target/
+.rustlings/
+.rustlings-state.json*
+rust-project.json
+";
+
+/// Write the exercises shipped with rustlings to a new workspace in `dir`,
+/// optionally making it a git repository. An existing workspace is only
+/// overwritten with `force`.
+pub fn init(dir: &Path, git: bool, force: bool) -> Result<(), String> {
+    if dir.join("info.toml").exists() && !force {
+        return Err(format!(
+            "There already is a rustlings workspace in {}, use --force to overwrite it",
+            dir.display()
+        ));
+    }
+
+    let write_error = |path: &Path, e| format!("Failed to write {}: {e}", path.display());
+    // The solutions stay in rustlings, which shows them once they're earned
+    let workspace_files = pristine::FILES
+        .iter()
+        .filter(|(file, _)| !Path::new(file).starts_with(exercise::SOLUTIONS_DIR));
+    for (file, content) in workspace_files {
+        let path = dir.join(file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| write_error(parent, e))?;
+        }
+        fs::write(&path, content).map_err(|e| write_error(&path, e))?;
+    }
+    let gitignore = dir.join(".gitignore");
+    fs::write(&gitignore, GITIGNORE).map_err(|e| write_error(&gitignore, e))?;
+
+    if git && !dir.join(".git").exists() {
+        let status = Command::new("git")
+            .arg("init")
+            .current_dir(dir)
+            .stdout(Stdio::null())
+            .status();
+        match status {
+            Ok(status) if status.success() => {}
+            _ => return Err(format!("Failed to run `git init` in {}", dir.display())),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,223 @@
+use crate::exercise::Exercise;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+/// A JUnit XML report with one testsuite per section of exercises,
+/// which is the directory they are in, and one testcase per exercise
+pub struct Report {
+    suites: Vec<Suite>,
+}
+
+struct Suite {
+    name: String,
+    cases: Vec<Case>,
+}
+
+struct Case {
+    name: String,
+    file: String,
+    time: Duration,
+    result: CaseResult,
+}
+
+/// What happened to an exercise, as far as the report is concerned
+pub enum CaseResult {
+    Passed,
+    Failed {
+        /// What kind of failure this is, like `compile_failed`
+        kind: &'static str,
+        message: String,
+        /// The compiler or test harness output, without colors
+        output: String,
+    },
+    Skipped {
+        message: String,
+    },
+}
+
+impl Report {
+    /// Start a report of the given exercises, all skipped until recorded
+    pub fn new<'a>(exercises: impl IntoIterator<Item = &'a Exercise>) -> Self {
+        let mut report = Report { suites: Vec::new() };
+        for exercise in exercises {
+            let section = exercise.section();
+            let index = match report.suites.iter().position(|s| s.name == section) {
+                Some(index) => index,
+                None => {
+                    report.suites.push(Suite {
+                        name: section,
+                        cases: Vec::new(),
+                    });
+                    report.suites.len() - 1
+                }
+            };
+            report.suites[index].cases.push(Case {
+                name: exercise.name.clone(),
+                file: exercise.path.display().to_string(),
+                time: Duration::ZERO,
+                result: CaseResult::Skipped {
+                    message: "not verified".to_string(),
+                },
+            });
+        }
+        report
+    }
+
+    /// Record the result of an exercise that is part of the report
+    pub fn record(&mut self, exercise: &Exercise, time: Duration, result: CaseResult) {
+        let case = self
+            .suites
+            .iter_mut()
+            .flat_map(|suite| suite.cases.iter_mut())
+            .find(|case| case.name == exercise.name);
+        if let Some(case) = case {
+            case.time = time;
+            case.result = result;
+        }
+    }
+
+    pub fn to_xml(&self) -> String {
+        let cases = || self.suites.iter().flat_map(|suite| suite.cases.iter());
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(xml, "<testsuites name=\"rustlings\"{}>", counts(cases()));
+        for suite in &self.suites {
+            let _ = writeln!(
+                xml,
+                "  <testsuite name=\"{}\"{}>",
+                escape(&suite.name),
+                counts(suite.cases.iter())
+            );
+            for case in &suite.cases {
+                let _ = write!(
+                    xml,
+                    "    <testcase name=\"{}\" classname=\"{}\" file=\"{}\" time=\"{:.3}\"",
+                    escape(&case.name),
+                    escape(&suite.name),
+                    escape(&case.file),
+                    case.time.as_secs_f64()
+                );
+                match &case.result {
+                    CaseResult::Passed => xml.push_str("/>\n"),
+                    CaseResult::Failed {
+                        kind,
+                        message,
+                        output,
+                    } => {
+                        let _ = writeln!(
+                            xml,
+                            ">\n      <failure type=\"{kind}\" message=\"{}\">{}</failure>\n    </testcase>",
+                            escape(message),
+                            escape(output)
+                        );
+                    }
+                    CaseResult::Skipped { message } => {
+                        let _ = writeln!(
+                            xml,
+                            ">\n      <skipped message=\"{}\"/>\n    </testcase>",
+                            escape(message)
+                        );
+                    }
+                }
+            }
+            xml.push_str("  </testsuite>\n");
+        }
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_xml())
+    }
+}
+
+// The attributes with the number of tests, failures and skipped tests and the total time
+fn counts<'a>(cases: impl Iterator<Item = &'a Case>) -> String {
+    let (mut tests, mut failures, mut skipped, mut time) = (0, 0, 0, Duration::ZERO);
+    for case in cases {
+        tests += 1;
+        time += case.time;
+        match case.result {
+            CaseResult::Passed => {}
+            CaseResult::Failed { .. } => failures += 1,
+            CaseResult::Skipped { .. } => skipped += 1,
+        }
+    }
+    format!(
+        " tests=\"{tests}\" failures=\"{failures}\" errors=\"0\" skipped=\"{skipped}\" time=\"{:.3}\"",
+        time.as_secs_f64()
+    )
+}
+
+// Escape text for attributes and elements, dropping
+// the control characters XML doesn't allow at all
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::exercise::{test_exercise, Mode};
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape("a < b && \"c\"\u{1b}[31m"),
+            "a &lt; b &amp;&amp; &quot;c&quot;[31m"
+        );
+    }
+
+    #[test]
+    fn test_report_by_section() {
+        let exercises = [
+            test_exercise("intro1", "exercises/intro/intro1.rs", Mode::Compile),
+            test_exercise(
+                "variables1",
+                "exercises/variables/variables1.rs",
+                Mode::Compile,
+            ),
+            test_exercise("intro2", "exercises/intro/intro2.rs", Mode::Compile),
+        ];
+        let mut report = Report::new(&exercises);
+        report.record(
+            &exercises[0],
+            Duration::from_millis(1500),
+            CaseResult::Passed,
+        );
+        report.record(
+            &exercises[2],
+            Duration::ZERO,
+            CaseResult::Failed {
+                kind: "compile_failed",
+                message: "1 error".into(),
+                output: "error[E0308]: mismatched <types>".into(),
+            },
+        );
+
+        let xml = report.to_xml();
+        assert!(xml.contains(
+            "<testsuites name=\"rustlings\" tests=\"3\" failures=\"1\" errors=\"0\" skipped=\"1\" time=\"1.500\">"
+        ));
+        assert!(xml.contains("<testsuite name=\"intro\" tests=\"2\" failures=\"1\""));
+        assert!(xml.contains("<testcase name=\"intro1\" classname=\"intro\" file=\"exercises/intro/intro1.rs\" time=\"1.500\"/>"));
+        assert!(xml.contains("mismatched &lt;types&gt;</failure>"));
+        assert!(xml.contains("<skipped message=\"not verified\"/>"));
+        assert!(xml.find("intro2").unwrap() < xml.find("variables1").unwrap());
+    }
+}
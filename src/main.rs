@@ -1,6 +1,8 @@
 use crate::exercise::{Exercise, ExerciseList};
+use crate::output::{Format, ListEntry, Status};
+use crate::progress::Progress;
 use crate::project::RustAnalyzerProject;
-use crate::run::{reset, run};
+use crate::run::run;
 use crate::verify::verify;
 use argh::FromArgs;
 use console::Emoji;
@@ -9,34 +11,74 @@ use notify::{RecommendedWatcher, RecursiveMode, Watcher};
 use std::ffi::OsStr;
 use std::fs;
 use std::io::{self, prelude::*};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::mpsc::{channel, RecvTimeoutError};
-use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
 #[macro_use]
 mod ui;
 
+mod cache;
+mod changes;
+mod dev;
+mod diagnostics;
+mod diff;
 mod exercise;
+mod expected;
+mod hint;
+mod init;
+mod junit;
+mod output;
+mod pack;
+mod pristine;
+mod progress;
 mod project;
+mod reset;
 mod run;
+mod session;
+mod solution;
 mod verify;
+mod workspace;
 
 // In sync with crate version
 const VERSION: &str = "5.5.1";
 
 #[derive(FromArgs, PartialEq, Debug)]
 /// Rustlings is a collection of small exercises to get you used to writing and reading Rust code
+#[argh(
+    error_code(1, "Something else went wrong, like an unknown exercise."),
+    error_code(2, "An exercise failed to compile, or clippy wasn't happy with it."),
+    error_code(3, "An exercise failed its tests, or its binary exited with an error."),
+    error_code(4, "An exercise still has its `I AM NOT DONE` marker."),
+    error_code(
+        5,
+        "An exercise was stopped for exceeding a time, memory or output limit."
+    ),
+    error_code(6, "An exercise didn't print its expected output."),
+    error_code(7, "A compile_fail exercise compiled, or failed with other errors.")
+)]
 struct Args {
     /// show outputs from the test exercises
     #[argh(switch)]
     nocapture: bool,
+    /// the format of the results of `verify`, `run` and `list`:
+    /// `human` (default) or `json`
+    #[argh(option, default = "Format::Human")]
+    format: Format,
     /// show the executable version
     #[argh(switch, short = 'v')]
     version: bool,
+    /// the directory with the exercises and info.toml, found by looking up
+    /// from the current directory unless given or set in RUSTLINGS_HOME
+    #[argh(option)]
+    workspace: Option<PathBuf>,
+    /// the pack of exercises to use, see `rustlings pack list`
+    #[argh(option)]
+    pack: Option<String>,
     #[argh(subcommand)]
     nested: Option<Subcommands>,
 }
@@ -44,19 +86,51 @@ struct Args {
 #[derive(FromArgs, PartialEq, Debug)]
 #[argh(subcommand)]
 enum Subcommands {
+    Init(InitArgs),
     Verify(VerifyArgs),
     Watch(WatchArgs),
     Run(RunArgs),
     Reset(ResetArgs),
+    Diff(DiffArgs),
     Hint(HintArgs),
+    Solution(SolutionArgs),
     List(ListArgs),
     Lsp(LspArgs),
+    Cache(CacheArgs),
+    Pack(PackArgs),
+    Dev(DevArgs),
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "init")]
+/// Creates a new workspace with all the exercises to get started
+struct InitArgs {
+    #[argh(positional, default = "PathBuf::from(\"rustlings\")")]
+    /// the directory to create the workspace in, `rustlings` by default
+    dir: PathBuf,
+    #[argh(switch)]
+    /// make the workspace a git repository
+    git: bool,
+    #[argh(switch)]
+    /// overwrite the files of an existing workspace
+    force: bool,
 }
 
 #[derive(FromArgs, PartialEq, Debug)]
 #[argh(subcommand, name = "verify")]
 /// Verifies all exercises according to the recommended order
-struct VerifyArgs {}
+struct VerifyArgs {
+    /// number of exercises to compile and test in parallel
+    #[argh(option, short = 'j', default = "1")]
+    jobs: usize,
+    /// write a JUnit XML report of the verified exercises to the given file
+    #[argh(option)]
+    junit: Option<PathBuf>,
+    /// carry on past exercises that fail or are still pending,
+    /// exiting with the code of the first one
+    #[argh(switch)]
+    keep_going: bool,
+}
 
 #[derive(FromArgs, PartialEq, Debug)]
 #[argh(subcommand, name = "watch")]
@@ -78,20 +152,63 @@ struct RunArgs {
 
 #[derive(FromArgs, PartialEq, Debug)]
 #[argh(subcommand, name = "reset")]
-/// Resets a single exercise using "git stash -- <filename>"
+/// Resets exercises to their original state, backing up your versions
 struct ResetArgs {
     #[argh(positional)]
     /// the name of the exercise
-    name: String,
+    name: Option<String>,
+    #[argh(option)]
+    /// reset all exercises of a section, like `intro`
+    section: Option<String>,
+    #[argh(switch)]
+    /// reset all exercises
+    all: bool,
+    #[argh(switch, short = 'y')]
+    /// don't ask for confirmation
+    yes: bool,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "diff")]
+/// Shows how your version of an exercise differs from the original
+struct DiffArgs {
+    #[argh(positional)]
+    /// the name of the exercise
+    name: Option<String>,
+    #[argh(switch)]
+    /// show the changes to all exercises
+    all: bool,
+    #[argh(switch, short = 'w')]
+    /// ignore changes to whitespace and comments,
+    /// like the removed `I AM NOT DONE` line
+    ignore_trivial: bool,
 }
 
 #[derive(FromArgs, PartialEq, Debug)]
 #[argh(subcommand, name = "hint")]
-/// Returns a hint for the given exercise
+/// Returns a hint for the given exercise, revealing one more level each time
 struct HintArgs {
     #[argh(positional)]
     /// the name of the exercise
     name: String,
+    #[argh(switch)]
+    /// show all levels of the hint
+    all: bool,
+    #[argh(option)]
+    /// show the given level of the hint, starting at 1
+    level: Option<usize>,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "solution")]
+/// Shows the reference solution of an exercise you have done
+struct SolutionArgs {
+    #[argh(positional)]
+    /// the name of the exercise
+    name: String,
+    #[argh(switch)]
+    /// show how your code differs from the solution
+    diff: bool,
 }
 
 #[derive(FromArgs, PartialEq, Debug)]
@@ -99,6 +216,83 @@ struct HintArgs {
 /// Enable rust-analyzer for exercises
 struct LspArgs {}
 
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "cache")]
+/// Manages the cache of compilation and test results
+struct CacheArgs {
+    #[argh(subcommand)]
+    nested: CacheSubcommands,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand)]
+enum CacheSubcommands {
+    Clean(CacheCleanArgs),
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "clean")]
+/// Removes all cached results
+struct CacheCleanArgs {}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "pack")]
+/// Manages the packs of exercises of the workspace
+struct PackArgs {
+    #[argh(subcommand)]
+    nested: PackSubcommands,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand)]
+enum PackSubcommands {
+    Add(PackAddArgs),
+    List(PackListArgs),
+    Use(PackUseArgs),
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "add")]
+/// Installs a pack from a directory or a tarball
+struct PackAddArgs {
+    #[argh(positional)]
+    /// the directory or tarball with the info.toml of the pack
+    source: PathBuf,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "list")]
+/// Lists the packs of the workspace
+struct PackListArgs {}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "use")]
+/// Uses the given pack from now on, unless --pack is given
+struct PackUseArgs {
+    #[argh(positional)]
+    /// the name of the pack
+    name: String,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "dev")]
+/// Tools for writing exercises
+struct DevArgs {
+    #[argh(subcommand)]
+    nested: DevSubcommands,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand)]
+enum DevSubcommands {
+    Check(DevCheckArgs),
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "check")]
+/// Checks info.toml and the exercises for mistakes
+struct DevCheckArgs {}
+
 #[derive(FromArgs, PartialEq, Debug)]
 #[argh(subcommand, name = "list")]
 /// Lists the exercises available in Rustlings
@@ -122,7 +316,7 @@ struct ListArgs {
 }
 
 fn main() {
-    let args: Args = argh::from_env();
+    let mut args: Args = argh::from_env();
 
     if args.version {
         println!("v{VERSION}");
@@ -133,15 +327,84 @@ fn main() {
         println!("\n{WELCOME}\n");
     }
 
-    if !Path::new("info.toml").exists() {
+    // A workspace is created from outside of one
+    if let Some(Subcommands::Init(subargs)) = &args.nested {
+        if let Err(e) = init::init(&subargs.dir, subargs.git, subargs.force) {
+            println!("{e}");
+            std::process::exit(1);
+        }
+        success!("Created a rustlings workspace in {}", subargs.dir.display());
+        println!(
+            "Run `cd {}` and `rustlings watch` to get started!",
+            subargs.dir.display()
+        );
+        std::process::exit(0);
+    }
+
+    let workspace = workspace::find(args.workspace.as_deref()).unwrap_or_else(|e| {
+        println!("{e}");
         println!(
-            "{} must be run from the rustlings directory",
+            "{} must be run from inside the rustlings directory",
             std::env::current_exe().unwrap().to_str().unwrap()
         );
-        println!("Try `cd rustlings/`!");
+        println!("Try `cd rustlings/`, or create it with `rustlings init`!");
+        std::process::exit(1);
+    });
+    // Paths given on the command line are relative to where rustlings was run,
+    // everything else is relative to the root of the workspace
+    let cwd = std::env::current_dir().unwrap_or_default();
+    match &mut args.nested {
+        Some(Subcommands::Verify(VerifyArgs {
+            junit: Some(path), ..
+        })) => *path = cwd.join(&path),
+        Some(Subcommands::Pack(PackArgs {
+            nested: PackSubcommands::Add(subargs),
+        })) => subargs.source = cwd.join(&subargs.source),
+        _ => {}
+    }
+    if let Err(e) = std::env::set_current_dir(&workspace.root) {
+        println!(
+            "Failed to enter the workspace {}: {e}",
+            workspace.root.display()
+        );
         std::process::exit(1);
     }
 
+    // Packs are used from their own directory, where their progress is kept.
+    // They are managed from the root of the workspace.
+    let pack = args
+        .pack
+        .clone()
+        .or(workspace.pack)
+        .or_else(pack::configured);
+    if let (Some(name), false) = (pack, matches!(args.nested, Some(Subcommands::Pack(_)))) {
+        let entered = pack::dir(&name).and_then(|dir| {
+            std::env::set_current_dir(&dir)
+                .map_err(|e| format!("Failed to enter the pack {}: {e}", dir.display()))
+        });
+        if let Err(e) = entered {
+            println!("{e}");
+            std::process::exit(1);
+        }
+    }
+
+    // Checking doesn't need a valid info.toml, it tells what's wrong with it
+    if let Some(Subcommands::Dev(DevArgs {
+        nested: DevSubcommands::Check(_),
+    })) = &args.nested
+    {
+        match dev::check() {
+            Ok(exercises) => {
+                success!("Checked {} exercises and found no problems", exercises);
+                std::process::exit(0);
+            }
+            Err(problems) => {
+                warn!("Found {} problem(s) with the exercises", problems);
+                std::process::exit(1);
+            }
+        }
+    }
+
     if !rustc_exists() {
         println!("We cannot find `rustc`.");
         println!("Try running `rustc --version` to diagnose your problem.");
@@ -149,8 +412,23 @@ fn main() {
         std::process::exit(1);
     }
 
-    let toml_str = &fs::read_to_string("info.toml").unwrap();
-    let exercises = toml::from_str::<ExerciseList>(toml_str).unwrap().exercises;
+    session::init();
+
+    let exercise_list = fs::read_to_string("info.toml")
+        .map_err(|e| e.to_string())
+        .and_then(|text| toml::from_str::<ExerciseList>(&text).map_err(|e| e.to_string()))
+        .unwrap_or_else(|e| {
+            println!("Failed to load info.toml: {e}");
+            println!("Run `rustlings dev check` to find out what's wrong with it.");
+            std::process::exit(1);
+        });
+    let pack_name = exercise_list
+        .pack
+        .as_ref()
+        .map_or(pack::DEFAULT_PACK, |pack| &pack.name)
+        .to_string();
+    let exercises = exercise_list.into_exercises();
+    let mut progress = Progress::load();
     let verbose = args.nocapture;
 
     let command = args.nested.unwrap_or_else(|| {
@@ -159,8 +437,10 @@ fn main() {
     });
     match command {
         Subcommands::List(subargs) => {
-            if !subargs.paths && !subargs.names {
-                println!("{:<17}\t{:<46}\t{:<7}", "Name", "Path", "Status");
+            let json = args.format == Format::Json;
+            let mut entries = Vec::new();
+            if !subargs.paths && !subargs.names && !json {
+                println!("{:<17}\t{:<46}\t{:<7}\tPack", "Name", "Path", "Status");
             }
             let mut exercises_done: u16 = 0;
             let filters = subargs.filter.clone().unwrap_or_default().to_lowercase();
@@ -169,25 +449,34 @@ fn main() {
                 let filter_cond = filters
                     .split(',')
                     .filter(|f| !f.trim().is_empty())
-                    .any(|f| e.name.contains(&f) || fname.contains(&f));
-                let status = if e.looks_done() {
+                    .any(|f| e.name.contains(f) || fname.contains(f));
+                let done = progress.is_done(e);
+                let status = if done {
                     exercises_done += 1;
                     "Done"
                 } else {
                     "Pending"
                 };
                 let solve_cond = {
-                    (e.looks_done() && subargs.solved)
-                        || (!e.looks_done() && subargs.unsolved)
+                    (done && subargs.solved)
+                        || (!done && subargs.unsolved)
                         || (!subargs.solved && !subargs.unsolved)
                 };
-                if solve_cond && (filter_cond || subargs.filter.is_none()) {
+                if solve_cond && (filter_cond || subargs.filter.is_none()) && json {
+                    entries.push(ListEntry {
+                        name: &e.name,
+                        path: &e.path,
+                        mode: e.mode,
+                        status: if done { Status::Done } else { Status::Pending },
+                        pack: &pack_name,
+                    });
+                } else if solve_cond && (filter_cond || subargs.filter.is_none()) {
                     let line = if subargs.paths {
                         format!("{fname}\n")
                     } else if subargs.names {
                         format!("{}\n", e.name)
                     } else {
-                        format!("{:<17}\t{fname:<46}\t{status:<7}\n", e.name)
+                        format!("{:<17}\t{fname:<46}\t{status:<7}\t{pack_name}\n", e.name)
                     };
                     // Somehow using println! leads to the binary panicking
                     // when its output is piped.
@@ -204,6 +493,10 @@ fn main() {
                     }
                 }
             });
+            if json {
+                output::emit(&entries);
+                std::process::exit(0);
+            }
             let percentage_progress = exercises_done as f32 / exercises.len() as f32 * 100.0;
             println!(
                 "Progress: You completed {} / {} exercises ({:.1} %).",
@@ -215,26 +508,131 @@ fn main() {
         }
 
         Subcommands::Run(subargs) => {
-            let exercise = find_exercise(&subargs.name, &exercises);
+            let exercise = find_exercise(&subargs.name, &exercises, &progress);
 
-            run(exercise, verbose).unwrap_or_else(|_| std::process::exit(1));
+            run(exercise, &mut progress, verbose, args.format)
+                .unwrap_or_else(|failure| std::process::exit(failure.exit_code()));
         }
 
         Subcommands::Reset(subargs) => {
-            let exercise = find_exercise(&subargs.name, &exercises);
+            let to_reset: Vec<&Exercise> = match (&subargs.name, &subargs.section, subargs.all) {
+                (Some(name), None, false) => vec![find_exercise(name, &exercises, &progress)],
+                (None, Some(section), false) => {
+                    let in_section: Vec<_> = exercises
+                        .iter()
+                        .filter(|e| e.section() == *section)
+                        .collect();
+                    if in_section.is_empty() {
+                        println!("No exercise found in the section '{section}'!");
+                        std::process::exit(1);
+                    }
+                    in_section
+                }
+                (None, None, true) => exercises.iter().collect(),
+                _ => {
+                    println!("Give either the name of an exercise, --section or --all");
+                    std::process::exit(1);
+                }
+            };
 
-            reset(exercise).unwrap_or_else(|_| std::process::exit(1));
+            reset::reset(&to_reset, subargs.yes).unwrap_or_else(|e| {
+                println!("{e}");
+                std::process::exit(1);
+            });
         }
 
+        Subcommands::Diff(subargs) => match (&subargs.name, subargs.all) {
+            (Some(name), false) => {
+                let exercise = find_exercise(name, &exercises, &progress);
+                match changes::show(exercise, subargs.ignore_trivial) {
+                    Ok(true) => {}
+                    Ok(false) => println!("{} is the same as the original.", exercise.name),
+                    Err(e) => {
+                        println!("{e}");
+                        std::process::exit(1);
+                    }
+                }
+            }
+            (None, true) => {
+                // Exercises that aren't shipped with rustlings have nothing to compare with
+                let changed = exercises
+                    .iter()
+                    .filter(|e| changes::show(e, subargs.ignore_trivial) == Ok(true))
+                    .count();
+                if changed == 0 {
+                    println!("All exercises are the same as the original ones.");
+                }
+            }
+            _ => {
+                println!("Give either the name of an exercise or --all");
+                std::process::exit(1);
+            }
+        },
+
         Subcommands::Hint(subargs) => {
-            let exercise = find_exercise(&subargs.name, &exercises);
+            let exercise = find_exercise(&subargs.name, &exercises, &progress);
+            let level = match (subargs.all, subargs.level) {
+                (true, Some(_)) => {
+                    println!("Only one of --all and --level can be given");
+                    std::process::exit(1);
+                }
+                (true, None) => hint::Level::All,
+                (false, Some(level)) => hint::Level::Exactly(level),
+                (false, None) => hint::Level::Next,
+            };
+
+            hint::show(exercise, &mut progress, level).unwrap_or_else(|e| {
+                println!("{e}");
+                std::process::exit(1);
+            });
+        }
 
-            println!("{}", exercise.hint);
+        Subcommands::Solution(subargs) => {
+            let exercise = find_exercise(&subargs.name, &exercises, &progress);
+            if !progress.is_done(exercise) {
+                println!(
+                    "The solution of {} is shown once you have done it. Keep going!",
+                    exercise.name
+                );
+                std::process::exit(1);
+            }
+
+            solution::show(exercise, subargs.diff).unwrap_or_else(|e| {
+                println!("{e}");
+                std::process::exit(1);
+            });
         }
 
-        Subcommands::Verify(_subargs) => {
-            verify(&exercises, (0, exercises.len()), verbose, false)
-                .unwrap_or_else(|_| std::process::exit(1));
+        Subcommands::Verify(subargs) => {
+            let options = verify::Options {
+                verbose,
+                success_hints: false,
+                offer_solutions: false,
+                jobs: subargs.jobs,
+                format: args.format,
+                keep_going: subargs.keep_going,
+            };
+            let mut report = subargs
+                .junit
+                .as_ref()
+                .map(|_| junit::Report::new(&exercises));
+            let result = verify(
+                &exercises,
+                (0, exercises.len()),
+                &mut progress,
+                &options,
+                report.as_mut(),
+            );
+            if let (Some(path), Some(report)) = (&subargs.junit, &report) {
+                if let Err(e) = report.write(path) {
+                    println!(
+                        "Failed to write the JUnit report to {}: {e}",
+                        path.display()
+                    );
+                    std::process::exit(1);
+                }
+            }
+            result.unwrap_or_else(|(_, failure)| std::process::exit(failure.exit_code()));
         }
 
         Subcommands::Lsp(_subargs) => {
@@ -243,7 +641,7 @@ fn main() {
                 .get_sysroot_src()
                 .expect("Couldn't find toolchain path, do you have `rustc` installed?");
             project
-                .exercises_to_json()
+                .exercises_to_json(&exercises)
                 .expect("Couldn't parse rustlings exercises files");
 
             if project.crates.is_empty() {
@@ -256,35 +654,88 @@ fn main() {
             }
         }
 
-        Subcommands::Watch(_subargs) => match watch(&exercises, verbose, _subargs.success_hints) {
-            Err(e) => {
-                println!(
-                    "Error: Could not watch your progress. Error message was {:?}.",
-                    e
-                );
-                println!("Most likely you've run out of disk space or your 'inotify limit' has been reached.");
+        Subcommands::Pack(subargs) => {
+            let result = match subargs.nested {
+                PackSubcommands::Add(subargs) => pack::add(&subargs.source).map(|pack| {
+                    let version = pack.version.map(|v| format!(" v{v}")).unwrap_or_default();
+                    success!(
+                        "Installed {}{} in {}",
+                        pack.name,
+                        version,
+                        pack.dir.display()
+                    );
+                    println!(
+                        "Use it with `rustlings --pack {}` or `rustlings pack use {}`",
+                        pack.name, pack.name
+                    );
+                }),
+                PackSubcommands::List(_) => pack::list().map(|packs| {
+                    let selected = pack::configured();
+                    for pack in packs {
+                        let marker = if selected.as_ref() == Some(&pack.name) {
+                            "*"
+                        } else {
+                            " "
+                        };
+                        let version = pack.version.unwrap_or_default();
+                        println!(
+                            "{marker} {:<17}\t{version:<9}\t{}",
+                            pack.name,
+                            pack.dir.display()
+                        );
+                    }
+                }),
+                PackSubcommands::Use(subargs) => pack::select(&subargs.name)
+                    .map(|_| println!("Using the pack {} from now on", subargs.name)),
+            };
+            result.unwrap_or_else(|e| {
+                println!("{e}");
                 std::process::exit(1);
-            }
-            Ok(WatchStatus::Finished) => {
-                println!(
-                    "{emoji} All exercises completed! {emoji}",
-                    emoji = Emoji("🎉", "★")
-                );
-                println!("\n{FENISH_LINE}\n");
-            }
-            Ok(WatchStatus::Unfinished) => {
-                println!("We hope you're enjoying learning about Rust!");
-                println!("If you want to continue working on the exercises at a later point, you can simply run `rustlings watch` again");
+            });
+        }
+
+        Subcommands::Cache(subargs) => match subargs.nested {
+            CacheSubcommands::Clean(_) => {
+                if let Err(e) = cache::clean() {
+                    println!("Failed to clean the cache: {e}");
+                    std::process::exit(1);
+                }
+                println!("Cleaned the cache");
             }
         },
+
+        // Handled before loading the exercises
+        Subcommands::Init(_) | Subcommands::Dev(_) => unreachable!(),
+
+        Subcommands::Watch(_subargs) => {
+            match watch(&exercises, &mut progress, verbose, _subargs.success_hints) {
+                Err(e) => {
+                    println!(
+                        "Error: Could not watch your progress. Error message was {:?}.",
+                        e
+                    );
+                    println!("Most likely you've run out of disk space or your 'inotify limit' has been reached.");
+                    std::process::exit(1);
+                }
+                Ok(WatchStatus::Finished) => {
+                    println!(
+                        "{emoji} All exercises completed! {emoji}",
+                        emoji = Emoji("🎉", "★")
+                    );
+                    println!("\n{FENISH_LINE}\n");
+                }
+                Ok(WatchStatus::Unfinished) => {
+                    println!("We hope you're enjoying learning about Rust!");
+                    println!("If you want to continue working on the exercises at a later point, you can simply run `rustlings watch` again");
+                }
+            }
+        }
     }
 }
 
-fn spawn_watch_shell(
-    failed_exercise_hint: &Arc<Mutex<Option<String>>>,
-    should_quit: Arc<AtomicBool>,
-) {
-    let failed_exercise_hint = Arc::clone(failed_exercise_hint);
+// The hints are shown by the watch loop, which owns the progress
+// they are recorded in, so the shell only asks for them
+fn spawn_watch_shell(hint_requests: Sender<()>, should_quit: Arc<AtomicBool>) {
     println!("Welcome to watch mode! You can type 'help' to get an overview of the commands you can use here.");
     thread::spawn(move || loop {
         let mut input = String::new();
@@ -292,9 +743,7 @@ fn spawn_watch_shell(
             Ok(_) => {
                 let input = input.trim();
                 if input == "hint" {
-                    if let Some(hint) = &*failed_exercise_hint.lock().unwrap() {
-                        println!("{hint}");
-                    }
+                    let _ = hint_requests.send(());
                 } else if input == "clear" {
                     println!("\x1B[2J\x1B[1;1H");
                 } else if input.eq("quit") {
@@ -302,7 +751,7 @@ fn spawn_watch_shell(
                     println!("Bye!");
                 } else if input.eq("help") {
                     println!("Commands available to you in watch mode:");
-                    println!("  hint   - prints the current exercise's hint");
+                    println!("  hint   - prints the next level of the current exercise's hint");
                     println!("  clear  - clears the screen");
                     println!("  quit   - quits watch mode");
                     println!("  !<cmd> - executes a command, like `!rustc --explain E0381`");
@@ -326,11 +775,11 @@ fn spawn_watch_shell(
     });
 }
 
-fn find_exercise<'a>(name: &str, exercises: &'a [Exercise]) -> &'a Exercise {
+fn find_exercise<'a>(name: &str, exercises: &'a [Exercise], progress: &Progress) -> &'a Exercise {
     if name.eq("next") {
         exercises
             .iter()
-            .find(|e| !e.looks_done())
+            .find(|e| !progress.is_done(e))
             .unwrap_or_else(|| {
                 println!("🎉 Congratulations! You have done all the exercises!");
                 println!("🔚 There are no more exercises to do next!");
@@ -354,6 +803,7 @@ enum WatchStatus {
 
 fn watch(
     exercises: &[Exercise],
+    progress: &mut Progress,
     verbose: bool,
     success_hints: bool,
 ) -> notify::Result<WatchStatus> {
@@ -371,46 +821,54 @@ fn watch(
 
     clear_screen();
 
-    let to_owned_hint = |t: &Exercise| t.hint.to_owned();
-    let failed_exercise_hint = match verify(
-        exercises.iter(),
-        (0, exercises.len()),
+    let options = verify::Options {
         verbose,
         success_hints,
+        offer_solutions: true,
+        jobs: 1,
+        format: Format::Human,
+        keep_going: false,
+    };
+    let mut failed_exercise = match verify(
+        exercises.iter(),
+        (0, exercises.len()),
+        progress,
+        &options,
+        None,
     ) {
         Ok(_) => return Ok(WatchStatus::Finished),
-        Err(exercise) => Arc::new(Mutex::new(Some(to_owned_hint(exercise)))),
+        Err((exercise, _)) => exercise,
     };
-    spawn_watch_shell(&failed_exercise_hint, Arc::clone(&should_quit));
+    let (hint_tx, hint_rx) = channel();
+    spawn_watch_shell(hint_tx, Arc::clone(&should_quit));
     loop {
-        match rx.recv_timeout(Duration::from_secs(1)) {
+        match rx.recv_timeout(Duration::from_millis(100)) {
             Ok(event) => match event {
-                DebouncedEvent::Create(b) | DebouncedEvent::Chmod(b) | DebouncedEvent::Write(b) => {
-                    if b.extension() == Some(OsStr::new("rs")) && b.exists() {
-                        let filepath = b.as_path().canonicalize().unwrap();
-                        let pending_exercises = exercises
-                            .iter()
-                            .find(|e| filepath.ends_with(&e.path))
-                            .into_iter()
-                            .chain(
-                                exercises
-                                    .iter()
-                                    .filter(|e| !e.looks_done() && !filepath.ends_with(&e.path)),
-                            );
-                        let num_done = exercises.iter().filter(|e| e.looks_done()).count();
-                        clear_screen();
-                        match verify(
-                            pending_exercises,
-                            (num_done, exercises.len()),
-                            verbose,
-                            success_hints,
-                        ) {
-                            Ok(_) => return Ok(WatchStatus::Finished),
-                            Err(exercise) => {
-                                let mut failed_exercise_hint = failed_exercise_hint.lock().unwrap();
-                                *failed_exercise_hint = Some(to_owned_hint(exercise));
-                            }
-                        }
+                DebouncedEvent::Create(b) | DebouncedEvent::Chmod(b) | DebouncedEvent::Write(b)
+                    if b.extension() == Some(OsStr::new("rs")) && b.exists() =>
+                {
+                    let filepath = b.as_path().canonicalize().unwrap();
+                    let pending_exercises: Vec<&Exercise> = exercises
+                        .iter()
+                        .find(|e| e.owns(&filepath))
+                        .into_iter()
+                        .chain(
+                            exercises
+                                .iter()
+                                .filter(|e| !progress.is_done(e) && !e.owns(&filepath)),
+                        )
+                        .collect();
+                    let num_done = exercises.iter().filter(|e| progress.is_done(e)).count();
+                    clear_screen();
+                    match verify(
+                        pending_exercises,
+                        (num_done, exercises.len()),
+                        progress,
+                        &options,
+                        None,
+                    ) {
+                        Ok(_) => return Ok(WatchStatus::Finished),
+                        Err((exercise, _)) => failed_exercise = exercise,
                     }
                 }
                 _ => {}
@@ -420,6 +878,9 @@ fn watch(
             }
             Err(e) => println!("watch error: {e:?}"),
         }
+        while hint_rx.try_recv().is_ok() {
+            let _ = hint::show(failed_exercise, progress, hint::Level::Next);
+        }
         // Check if we need to exit
         if should_quit.load(Ordering::SeqCst) {
             return Ok(WatchStatus::Unfinished);
@@ -429,7 +890,7 @@ fn watch(
 
 fn rustc_exists() -> bool {
     Command::new("rustc")
-        .args(&["--version"])
+        .args(["--version"])
         .stdout(Stdio::null())
         .spawn()
         .and_then(|mut child| child.wait())
@@ -0,0 +1,147 @@
+use crate::diagnostics::Diagnostic;
+use crate::exercise::Mode;
+use serde::Serialize;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// How results are reported on stdout
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Format {
+    /// Coloured text, meant to be read by people
+    Human,
+    /// JSON, meant to be read by scripts
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format `{s}`, expected `human` or `json`")),
+        }
+    }
+}
+
+/// Something that happened while verifying or running an exercise,
+/// emitted as one line of JSON with `--format json`.
+///
+/// Durations are in milliseconds. Results loaded from the cache keep
+/// the durations of the run that produced them and are marked as `cached`.
+#[derive(Serialize, Debug)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event<'a> {
+    Started {
+        exercise: &'a str,
+        path: &'a Path,
+        mode: Mode,
+    },
+    Compiled {
+        exercise: &'a str,
+        duration_ms: u64,
+        cached: bool,
+    },
+    CompileFailed {
+        exercise: &'a str,
+        duration_ms: u64,
+        cached: bool,
+        errors: usize,
+        warnings: usize,
+        /// The rendered diagnostics, without colors
+        output: String,
+        diagnostics: &'a [Diagnostic],
+    },
+    /// The binary or test harness failed, or was stopped by the runner
+    TestFailed {
+        exercise: &'a str,
+        /// The stdin fixture the binary was run with, if any
+        case: Option<&'a str>,
+        duration_ms: u64,
+        cached: bool,
+        /// The limit that got the binary or test harness stopped
+        limit_exceeded: Option<String>,
+        /// How stdout differs from the expected output, without colors
+        diff: Option<String>,
+        stdout: &'a str,
+        stderr: &'a str,
+    },
+    Passed {
+        exercise: &'a str,
+        case: Option<&'a str>,
+        duration_ms: u64,
+        cached: bool,
+        stdout: &'a str,
+        stderr: &'a str,
+    },
+    /// A compile_fail exercise compiled, or failed with other errors
+    WrongErrors {
+        exercise: &'a str,
+        duration_ms: u64,
+        cached: bool,
+        expected: &'a [String],
+        /// The errors it failed with, empty if it compiled
+        errors: &'a [String],
+        output: String,
+    },
+    /// The exercise passed but still has its `I AM NOT DONE` marker
+    PendingMarker { exercise: &'a str, line: usize },
+}
+
+/// An exercise as listed by `rustlings list`
+#[derive(Serialize, Debug)]
+pub struct ListEntry<'a> {
+    pub name: &'a str,
+    pub path: &'a Path,
+    pub mode: Mode,
+    pub status: Status,
+    pub pack: &'a str,
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Done,
+    Pending,
+}
+
+/// Print a value as a single line of JSON. Like the human output of `list`,
+/// exits quietly when whatever reads it stops, as `head` does.
+pub fn emit<T: Serialize>(value: &T) {
+    let line = serde_json::to_string(value).expect("Failed to serialize output");
+    let stdout = io::stdout();
+    let mut handle = stdout.lock();
+    if let Err(e) = writeln!(handle, "{line}") {
+        match e.kind() {
+            io::ErrorKind::BrokenPipe => process::exit(0),
+            _ => process::exit(1),
+        }
+    }
+}
+
+pub fn millis(duration: Duration) -> u64 {
+    duration.as_millis().try_into().unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_event_format() {
+        let event = Event::PendingMarker {
+            exercise: "intro1",
+            line: 3,
+        };
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"event":"pending_marker","exercise":"intro1","line":3}"#
+        );
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
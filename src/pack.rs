@@ -0,0 +1,190 @@
+use crate::exercise::{self, ExerciseList, PackInfo};
+use crate::pristine;
+use crate::session;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Where the packs are installed, relative to the root of the workspace
+pub const PACKS_DIR: &str = "packs";
+/// The name of the exercises of a workspace without a `[pack]` table
+pub const DEFAULT_PACK: &str = "rustlings";
+// Remembers the pack to use, relative to the root of the workspace
+const CONFIG_FILE: &str = ".rustlings/config.toml";
+
+#[derive(Deserialize, Serialize, Default)]
+struct Config {
+    pack: Option<String>,
+}
+
+/// A pack that can be used in the workspace
+pub struct Pack {
+    pub name: String,
+    pub version: Option<String>,
+    pub dir: PathBuf,
+}
+
+/// The pack of the info.toml in `dir`, named after the default pack if it has no name
+pub fn read(dir: &Path) -> Result<Pack, String> {
+    let manifest = dir.join("info.toml");
+    let text = fs::read_to_string(&manifest)
+        .map_err(|e| format!("Failed to read {}: {e}", manifest.display()))?;
+    let list: ExerciseList = toml::from_str(&text)
+        .map_err(|e| format!("{} is not a valid info.toml: {e}", manifest.display()))?;
+    let PackInfo { name, version } = list.pack.unwrap_or_else(|| PackInfo {
+        name: DEFAULT_PACK.to_string(),
+        version: None,
+    });
+    Ok(Pack {
+        name,
+        version,
+        dir: dir.to_path_buf(),
+    })
+}
+
+/// The packs of the workspace in the current directory,
+/// starting with its own exercises
+pub fn list() -> Result<Vec<Pack>, String> {
+    let mut packs = vec![read(Path::new("."))?];
+    let Ok(entries) = fs::read_dir(PACKS_DIR) else {
+        return Ok(packs);
+    };
+    let mut installed: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|dir| dir.join("info.toml").is_file())
+        .collect();
+    installed.sort();
+    for dir in installed {
+        let mut pack = read(&dir)?;
+        // The directory is what packs are selected by
+        pack.name = dir.file_name().unwrap().to_string_lossy().into_owned();
+        packs.push(pack);
+    }
+    Ok(packs)
+}
+
+/// The directory of the pack with the given name
+pub fn dir(name: &str) -> Result<PathBuf, String> {
+    let installed = Path::new(PACKS_DIR).join(name);
+    if installed.join("info.toml").is_file() {
+        return Ok(installed);
+    }
+    match read(Path::new(".")) {
+        Ok(own) if own.name == name => Ok(PathBuf::from(".")),
+        _ => Err(format!(
+            "There is no pack named '{name}', see `rustlings pack list`"
+        )),
+    }
+}
+
+/// The pack chosen with `rustlings pack use`, if any
+pub fn configured() -> Option<String> {
+    let text = fs::read_to_string(CONFIG_FILE).ok()?;
+    toml::from_str::<Config>(&text).ok()?.pack
+}
+
+/// Use the pack with the given name from now on
+pub fn select(name: &str) -> Result<(), String> {
+    dir(name)?;
+    let config = Config {
+        pack: Some(name.to_string()),
+    };
+    let text = toml::to_string(&config).map_err(|e| e.to_string())?;
+    fs::create_dir_all(Path::new(CONFIG_FILE).parent().unwrap())
+        .and_then(|_| fs::write(CONFIG_FILE, text))
+        .map_err(|e| format!("Failed to write {CONFIG_FILE}: {e}"))
+}
+
+/// Install the pack in a directory or a tarball, which is extracted with
+/// the `tar` command, so it may be compressed in any way that supports.
+/// The pack is named by the `[pack]` table of its info.toml.
+pub fn add(source: &Path) -> Result<Pack, String> {
+    let source_dir = if source.is_dir() {
+        source.to_path_buf()
+    } else {
+        extract(source)?
+    };
+    let pack_root = if source_dir.join("info.toml").is_file() {
+        source_dir.clone()
+    } else {
+        // Tarballs usually have everything in a single directory
+        let mut dirs = fs::read_dir(&source_dir)
+            .map_err(|e| format!("Failed to read {}: {e}", source.display()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|dir| dir.join("info.toml").is_file());
+        match (dirs.next(), dirs.next()) {
+            (Some(dir), None) => dir,
+            _ => return Err(format!("{} has no info.toml", source.display())),
+        }
+    };
+
+    let text = fs::read_to_string(pack_root.join("info.toml"))
+        .map_err(|e| format!("Failed to read the info.toml of {}: {e}", source.display()))?;
+    let list: ExerciseList = toml::from_str(&text)
+        .map_err(|e| format!("The info.toml of {} is not valid: {e}", source.display()))?;
+    let Some(PackInfo { name, version }) = list.pack else {
+        return Err(format!(
+            "The info.toml of {} has no [pack] table with the name of the pack",
+            source.display()
+        ));
+    };
+    let valid_name = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !valid_name {
+        return Err(format!(
+            "'{name}' can't be the name of a pack, it may only have letters, digits, '-', '_' and '.'"
+        ));
+    }
+
+    let dir = Path::new(PACKS_DIR).join(&name);
+    if dir.exists() {
+        return Err(format!(
+            "The pack '{name}' is already installed in {}, remove it first to replace it",
+            dir.display()
+        ));
+    }
+    // The snapshot is what the exercises are reset to, and where the
+    // solutions are shown from, which the pack's directory doesn't get
+    let copy_error = |e| format!("Failed to install {name}: {e}");
+    for file in exercise::crate_files(&pack_root).map_err(copy_error)? {
+        let relative = file.strip_prefix(&pack_root).unwrap();
+        let snapshot = dir.join(pristine::SNAPSHOT_DIR).join(relative);
+        let targets = if relative.starts_with(exercise::SOLUTIONS_DIR) {
+            vec![snapshot]
+        } else {
+            vec![dir.join(relative), snapshot]
+        };
+        for target in targets {
+            fs::create_dir_all(target.parent().unwrap())
+                .and_then(|_| fs::copy(&file, &target))
+                .map_err(copy_error)?;
+        }
+    }
+    Ok(Pack { name, version, dir })
+}
+
+// Extract a tarball into the session directory
+fn extract(tarball: &Path) -> Result<PathBuf, String> {
+    let dir = session::dir().join("pack");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to extract the pack: {e}"))?;
+    let status = Command::new("tar")
+        .arg("-xf")
+        .arg(tarball)
+        .arg("-C")
+        .arg(&dir)
+        .stdout(Stdio::null())
+        .status();
+    match status {
+        Ok(status) if status.success() => Ok(dir),
+        Ok(_) => Err(format!("Failed to extract {}", tarball.display())),
+        Err(e) => Err(format!(
+            "Failed to run `tar` to extract {}: {e}",
+            tarball.display()
+        )),
+    }
+}
@@ -0,0 +1,99 @@
+use crate::exercise::{self, Exercise, Mode};
+use std::borrow::Cow;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Where an installed pack keeps a copy of its original files,
+/// relative to the directory of the pack
+pub const SNAPSHOT_DIR: &str = ".rustlings/pristine";
+
+// The exercises, solutions and info.toml as they were shipped, embedded by build.rs
+// as `FILES`, pairs of a path with `/` separators and the file's content
+include!(concat!(env!("OUT_DIR"), "/pristine.rs"));
+
+/// The original files of an exercise, from the snapshot of the pack in the
+/// current directory, or else the ones shipped with rustlings.
+/// They are empty if there is no original of the exercise.
+pub fn files(exercise: &Exercise) -> Vec<(PathBuf, Cow<'static, [u8]>)> {
+    originals(&exercise.path, exercise.mode)
+}
+
+/// The files of the exercise's reference solution, from the same places
+/// as `files`. Workspaces don't get a copy of the solutions, so that they
+/// can only be seen with `rustlings solution`.
+pub fn solution_files(exercise: &Exercise) -> Vec<(PathBuf, Cow<'static, [u8]>)> {
+    exercise
+        .solution_path()
+        .map(|path| originals(&path, exercise.mode))
+        .unwrap_or_default()
+}
+
+// The original files at the path, a directory for cargo exercises
+fn originals(path: &Path, mode: Mode) -> Vec<(PathBuf, Cow<'static, [u8]>)> {
+    let snapshot = Path::new(SNAPSHOT_DIR);
+    if snapshot.is_dir() {
+        return snapshot_files(snapshot, path, mode).unwrap_or_default();
+    }
+    let key = key(path);
+    FILES
+        .iter()
+        .filter(|(path, _)| match mode {
+            Mode::Cargo => path
+                .strip_prefix(key.as_str())
+                .is_some_and(|rest| rest.starts_with('/')),
+            _ => *path == key,
+        })
+        .map(|(path, content)| (PathBuf::from(path), Cow::Borrowed(*content)))
+        .collect()
+}
+
+fn snapshot_files(
+    snapshot: &Path,
+    path: &Path,
+    mode: Mode,
+) -> Option<Vec<(PathBuf, Cow<'static, [u8]>)>> {
+    let original = snapshot.join(path);
+    let files = match mode {
+        Mode::Cargo => exercise::crate_files(&original).ok()?,
+        _ if original.is_file() => vec![original],
+        _ => return None,
+    };
+    files
+        .into_iter()
+        .map(|file| {
+            let content = fs::read(&file).ok()?;
+            let path = file.strip_prefix(snapshot).ok()?.to_path_buf();
+            Some((path, Cow::Owned(content)))
+        })
+        .collect()
+}
+
+// The key of a path in `FILES`
+fn key(path: &Path) -> String {
+    let components: Vec<_> = path
+        .components()
+        .filter(|c| !matches!(c, Component::CurDir))
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect();
+    components.join("/")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::exercise::test_exercise;
+
+    #[test]
+    fn test_files_of_shipped_exercise() {
+        let exercise = test_exercise("intro1", "./exercises/intro/intro1.rs", Mode::Compile);
+        let files = files(&exercise);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].0, Path::new("exercises/intro/intro1.rs"));
+        assert!(!files[0].1.is_empty());
+
+        let solution = solution_files(&exercise);
+        assert_eq!(solution.len(), 1);
+        assert_eq!(solution[0].0, Path::new("solutions/intro/intro1.rs"));
+        assert_ne!(solution[0].1, files[0].1);
+    }
+}
@@ -0,0 +1,207 @@
+use crate::exercise::Exercise;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// The file the progress is persisted in, relative to the workspace root
+const PROGRESS_FILE: &str = ".rustlings-state.json";
+
+/// Tracks which exercises have actually been verified.
+///
+/// An exercise only counts as done when its `I AM NOT DONE` marker is gone
+/// and its current source matches the content hash that was recorded the
+/// last time it compiled and passed. Editing a file drops it back to pending.
+#[derive(Serialize, Deserialize, Default, Debug)]
+pub struct Progress {
+    #[serde(skip)]
+    path: PathBuf,
+    #[serde(default)]
+    exercises: BTreeMap<String, ExerciseProgress>,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug)]
+struct ExerciseProgress {
+    // Content hash of the source that last compiled and passed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    verified_hash: Option<String>,
+    // How many levels of the exercise's hints have been revealed
+    #[serde(default, skip_serializing_if = "is_zero")]
+    hints_revealed: usize,
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+impl Progress {
+    /// Load the progress of the current workspace.
+    /// A missing or unreadable file starts out with no verified exercises.
+    pub fn load() -> Progress {
+        Progress::load_from(PROGRESS_FILE)
+    }
+
+    /// Load the progress from the given file instead of the workspace's
+    pub fn load_from(path: impl AsRef<Path>) -> Progress {
+        let path = path.as_ref();
+        let mut progress = fs::read_to_string(path)
+            .ok()
+            .and_then(|s| serde_json::from_str::<Progress>(&s).ok())
+            .unwrap_or_default();
+        progress.path = path.to_path_buf();
+        progress
+    }
+
+    /// Write the progress to disk, replacing the previous file atomically
+    pub fn save(&self) -> io::Result<()> {
+        // Each process writes its own temporary file, so concurrent saves don't clobber it
+        let tmp = self
+            .path
+            .with_extension(format!("json.{}.tmp", std::process::id()));
+        fs::write(
+            &tmp,
+            serde_json::to_vec_pretty(&self).expect("Failed to serialize to JSON"),
+        )?;
+        fs::rename(&tmp, &self.path)
+    }
+
+    /// Record that the exercise's current source compiled and passed
+    pub fn record(&mut self, exercise: &Exercise) {
+        let hash = match exercise.content_hash() {
+            Ok(hash) => hash,
+            Err(_) => return,
+        };
+        self.update(&exercise.name, |progress| {
+            progress.verified_hash = Some(hash);
+            true
+        });
+    }
+
+    /// How many levels of the exercise's hints have been revealed so far
+    pub fn hints_revealed(&self, exercise: &Exercise) -> usize {
+        self.exercises
+            .get(&exercise.name)
+            .map_or(0, |e| e.hints_revealed)
+    }
+
+    /// Record that the exercise's hints have been revealed up to the given level
+    pub fn reveal_hints(&mut self, exercise: &Exercise, level: usize) {
+        self.update(&exercise.name, |progress| {
+            if level <= progress.hints_revealed {
+                return false;
+            }
+            progress.hints_revealed = level;
+            true
+        });
+    }
+
+    // Apply a change to an exercise's progress and save it. Another rustlings
+    // (say `watch` and a `verify` in a second terminal) may have saved since
+    // this one loaded, so the file is read again and what it holds is kept.
+    fn update(&mut self, name: &str, change: impl FnOnce(&mut ExerciseProgress) -> bool) {
+        let mut on_disk = Progress::load_from(&self.path).exercises;
+        for (name, progress) in std::mem::take(&mut self.exercises) {
+            on_disk.entry(name).or_insert(progress);
+        }
+        self.exercises = on_disk;
+        if !change(self.exercises.entry(name.to_string()).or_default()) {
+            return;
+        }
+        if let Err(e) = self.save() {
+            ewarn!("Failed to save your progress: {}", e);
+        }
+    }
+
+    /// Check that the exercise has been verified in its current form
+    /// and the `I AM NOT DONE` marker has been removed
+    pub fn is_done(&self, exercise: &Exercise) -> bool {
+        let verified_hash = match self
+            .exercises
+            .get(&exercise.name)
+            .and_then(|e| e.verified_hash.as_ref())
+        {
+            Some(hash) => hash,
+            None => return false,
+        };
+        exercise.looks_done() && exercise.content_hash().ok().as_ref() == Some(verified_hash)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::exercise::{test_exercise, Mode};
+
+    #[test]
+    fn test_unverified_exercise_is_pending() {
+        let progress = Progress::default();
+        let finished = test_exercise(
+            "finished_exercise",
+            "tests/fixture/state/finished_exercise.rs",
+            Mode::Compile,
+        );
+        assert!(finished.looks_done());
+        assert!(!progress.is_done(&finished));
+    }
+
+    #[test]
+    fn test_recorded_exercise_is_done() {
+        let path = std::env::temp_dir().join(format!("rustlings_progress_{}", std::process::id()));
+        let mut progress = Progress::load_from(&path);
+        let finished = test_exercise(
+            "finished_exercise",
+            "tests/fixture/state/finished_exercise.rs",
+            Mode::Compile,
+        );
+        let pending = test_exercise(
+            "pending_exercise",
+            "tests/fixture/state/pending_exercise.rs",
+            Mode::Compile,
+        );
+        progress.record(&finished);
+        progress.record(&pending);
+        assert!(progress.is_done(&finished));
+        assert!(!progress.is_done(&pending));
+
+        let reloaded = Progress::load_from(&path);
+        assert!(reloaded.is_done(&finished));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_saving_keeps_progress_saved_by_others() {
+        let path =
+            std::env::temp_dir().join(format!("rustlings_progress_merge_{}", std::process::id()));
+        let finished = test_exercise(
+            "finished_exercise",
+            "tests/fixture/state/finished_exercise.rs",
+            Mode::Compile,
+        );
+        let mut first = Progress::load_from(&path);
+        let mut second = Progress::load_from(&path);
+        first.record(&finished);
+        second.reveal_hints(&finished, 2);
+
+        let reloaded = Progress::load_from(&path);
+        assert!(reloaded.is_done(&finished));
+        assert_eq!(reloaded.hints_revealed(&finished), 2);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_edited_exercise_is_pending() {
+        let mut progress = Progress::default();
+        let finished = test_exercise(
+            "finished_exercise",
+            "tests/fixture/state/finished_exercise.rs",
+            Mode::Compile,
+        );
+        progress
+            .exercises
+            .entry(finished.name.clone())
+            .or_default()
+            .verified_hash = Some("stale".to_string());
+        assert!(!progress.is_done(&finished));
+    }
+}
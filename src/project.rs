@@ -1,8 +1,9 @@
+use crate::exercise::{Exercise, Mode, DEFAULT_EDITION};
 use glob::glob;
 use serde::{Deserialize, Serialize};
 use std::env;
 use std::error::Error;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 /// Contains the structure of resulting rust-project.json file
@@ -38,16 +39,26 @@ impl RustAnalyzerProject {
         Ok(())
     }
 
-    /// If path contains .rs extension, add a crate to `rust-project.json`
-    fn path_to_json(&mut self, path: PathBuf) -> Result<(), Box<dyn Error>> {
+    /// If path contains .rs extension, add a crate to `rust-project.json`,
+    /// with the edition and cfg of the exercise it belongs to
+    fn path_to_json(
+        &mut self,
+        path: PathBuf,
+        exercises: &[Exercise],
+    ) -> Result<(), Box<dyn Error>> {
         if let Some(ext) = path.extension() {
             if ext == "rs" {
+                let exercise = exercises.iter().find(|e| belongs_to(e, &path));
+                // This allows rust_analyzer to work inside #[test] blocks
+                let mut cfg = vec!["test".to_string()];
+                cfg.extend(exercise.and_then(|e| e.cfg.clone()).unwrap_or_default());
                 self.crates.push(Crate {
                     root_module: path.display().to_string(),
-                    edition: "2021".to_string(),
+                    edition: exercise
+                        .map_or(DEFAULT_EDITION, |e| e.edition())
+                        .to_string(),
                     deps: Vec::new(),
-                    // This allows rust_analyzer to work inside #[test] blocks
-                    cfg: vec!["test".to_string()],
+                    cfg,
                 })
             }
         }
@@ -58,9 +69,9 @@ impl RustAnalyzerProject {
     /// Parse the exercises folder for .rs files, any matches will create
     /// a new `crate` in rust-project.json which allows rust-analyzer to
     /// treat it like a normal binary
-    pub fn exercises_to_json(&mut self) -> Result<(), Box<dyn Error>> {
+    pub fn exercises_to_json(&mut self, exercises: &[Exercise]) -> Result<(), Box<dyn Error>> {
         for path in glob("./exercises/**/*")? {
-            self.path_to_json(path?)?;
+            self.path_to_json(path?, exercises)?;
         }
         Ok(())
     }
@@ -86,7 +97,7 @@ impl RustAnalyzerProject {
 
         println!("Determined toolchain: {}\n", &toolchain);
 
-        self.sysroot_src = (std::path::Path::new(&*toolchain)
+        self.sysroot_src = (std::path::Path::new(toolchain)
             .join("lib")
             .join("rustlib")
             .join("src")
@@ -97,3 +108,14 @@ impl RustAnalyzerProject {
         Ok(())
     }
 }
+
+// Whether the file is the exercise's, or one in its directory for cargo exercises.
+// The paths are relative, a leading `./` is ignored.
+fn belongs_to(exercise: &Exercise, file: &Path) -> bool {
+    let path = exercise.path.strip_prefix(".").unwrap_or(&exercise.path);
+    let file = file.strip_prefix(".").unwrap_or(file);
+    match exercise.mode {
+        Mode::Cargo => file.starts_with(path),
+        _ => file == path,
+    }
+}
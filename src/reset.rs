@@ -0,0 +1,96 @@
+use crate::exercise::Exercise;
+use crate::pristine;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Where the learner's versions of reset files are kept,
+// in a directory per reset named after when it happened
+const BACKUPS_DIR: &str = ".rustlings/backups";
+
+/// Restore the original files of the exercises, after backing up the
+/// learner's versions of them. Asks for confirmation unless `yes` is set.
+pub fn reset(exercises: &[&Exercise], yes: bool) -> Result<(), String> {
+    let mut changed = Vec::new();
+    for exercise in exercises {
+        let files = pristine::files(exercise);
+        if files.is_empty() {
+            if let [_] = exercises {
+                return Err(format!(
+                    "There is no original copy of {exercise} to reset it to"
+                ));
+            }
+            warn!("There is no original copy of {}, skipping it", exercise);
+            continue;
+        }
+        for (path, original) in files {
+            if fs::read(&path).ok().as_deref() != Some(&*original) {
+                changed.push((path, original));
+            }
+        }
+    }
+    if changed.is_empty() {
+        println!("Nothing to reset, the exercises are in their original state.");
+        return Ok(());
+    }
+
+    println!("These files will be reset to their original state:");
+    for (path, _) in &changed {
+        println!("    {}", path.display());
+    }
+    if !yes && !confirm("Reset them? Your versions will be backed up. [y/N] ") {
+        println!("Nothing was reset.");
+        return Ok(());
+    }
+
+    let backups = backups_dir();
+    let mut backed_up = false;
+    for (path, original) in &changed {
+        if path.exists() {
+            let backup = backups.join(path);
+            create_parent(&backup)
+                .and_then(|_| fs::copy(path, &backup))
+                .map_err(|e| format!("Failed to back up {}: {e}", path.display()))?;
+            backed_up = true;
+        }
+        create_parent(path)
+            .and_then(|_| fs::write(path, original.as_ref()))
+            .map_err(|e| format!("Failed to reset {}: {e}", path.display()))?;
+    }
+    success!("Reset {} file(s) to their original state", changed.len());
+    if backed_up {
+        println!("Your versions are backed up in {}", backups.display());
+    }
+    Ok(())
+}
+
+// Ask a yes/no question on stdin, where anything but yes is a no
+fn confirm(question: &str) -> bool {
+    print!("{question}");
+    let _ = io::stdout().flush();
+    let mut answer = String::new();
+    match io::stdin().read_line(&mut answer) {
+        Ok(0) | Err(_) => {
+            println!();
+            false
+        }
+        Ok(_) => matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"),
+    }
+}
+
+// A new directory for the backups of this reset
+fn backups_dir() -> PathBuf {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_millis())
+        .unwrap_or_default();
+    Path::new(BACKUPS_DIR).join(millis.to_string())
+}
+
+fn create_parent(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(parent) => fs::create_dir_all(parent),
+        None => Ok(()),
+    }
+}
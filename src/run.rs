@@ -1,42 +1,38 @@
-use std::process::Command;
-
+use crate::diagnostics;
 use crate::exercise::{Exercise, Mode};
-use crate::verify::test;
+use crate::output::Format;
+use crate::progress::Progress;
+use crate::verify::{verify_single, Failure};
 use indicatif::ProgressBar;
+use std::time::Duration;
 
 // Invoke the rust compiler on the path of the given exercise,
 // and run the ensuing binary.
 // The verbose argument helps determine whether or not to show
 // the output from the test harnesses (if the mode of the exercise is test)
-pub fn run(exercise: &Exercise, verbose: bool) -> Result<(), ()> {
-    match exercise.mode {
-        Mode::Test => test(exercise, verbose)?,
-        Mode::Compile => compile_and_run(exercise)?,
-        Mode::Clippy => compile_and_run(exercise)?,
+// A successful run is recorded in the progress store.
+pub fn run(
+    exercise: &Exercise,
+    store: &mut Progress,
+    verbose: bool,
+    format: Format,
+) -> Result<(), Failure> {
+    match (format, exercise.mode) {
+        (Format::Human, Mode::Compile) if exercise.stdin.is_empty() => compile_and_run(exercise)?,
+        (Format::Human, Mode::Clippy) => compile_and_run(exercise)?,
+        _ => verify_single(exercise, verbose, format)?,
     }
+    store.record(exercise);
     Ok(())
 }
 
-// Resets the exercise by stashing the changes.
-pub fn reset(exercise: &Exercise) -> Result<(), ()> {
-    let command = Command::new("git")
-        .args(["stash", "--"])
-        .arg(&exercise.path)
-        .spawn();
-
-    match command {
-        Ok(_) => Ok(()),
-        Err(_) => Err(()),
-    }
-}
-
 // Invoke the rust compiler on the path of the given exercise
 // and run the ensuing binary.
 // This is strictly for non-test binaries, so output is displayed
-fn compile_and_run(exercise: &Exercise) -> Result<(), ()> {
+fn compile_and_run(exercise: &Exercise) -> Result<(), Failure> {
     let progress_bar = ProgressBar::new_spinner();
     progress_bar.set_message(format!("Compiling {exercise}..."));
-    progress_bar.enable_steady_tick(100);
+    progress_bar.enable_steady_tick(Duration::from_millis(100));
 
     let compilation_result = exercise.compile();
     let compilation = match compilation_result {
@@ -47,8 +43,12 @@ fn compile_and_run(exercise: &Exercise) -> Result<(), ()> {
                 "Compilation of {} failed!, Compiler error message:\n",
                 exercise
             );
+            print!("{}", diagnostics::render(&output.diagnostics));
             println!("{}", output.stderr);
-            return Err(());
+            if let Mode::Clippy = exercise.mode {
+                println!("Checked with these lints: {}", exercise.lints());
+            }
+            return Err(Failure::CompileFailed);
         }
     };
 
@@ -59,15 +59,31 @@ fn compile_and_run(exercise: &Exercise) -> Result<(), ()> {
     match result {
         Ok(output) => {
             println!("{}", output.stdout);
+            if let Some(diff) = exercise.output_diff(None, &output.stdout) {
+                warn!("Ran {} but it didn't print the expected output:", exercise);
+                print!("{diff}");
+                return Err(Failure::OutputMismatch);
+            }
             success!("Successfully ran {}", exercise);
+            if let Mode::Clippy = exercise.mode {
+                println!("Checked with these lints: {}", exercise.lints());
+            }
             Ok(())
         }
         Err(output) => {
             println!("{}", output.stdout);
             println!("{}", output.stderr);
 
-            warn!("Ran {} with errors", exercise);
-            Err(())
+            match output.limit_exceeded {
+                Some(limit) => {
+                    warn!("{} was stopped because it exceeded {}", exercise, limit);
+                    Err(Failure::LimitExceeded)
+                }
+                None => {
+                    warn!("Ran {} with errors", exercise);
+                    Err(Failure::RunFailed)
+                }
+            }
         }
     }
 }
@@ -0,0 +1,208 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+#[cfg(not(unix))]
+use std::time::{Duration, SystemTime};
+
+// Where the directories of the sessions are, relative to the workspace root
+const SESSIONS_DIR: &str = "target/rustlings";
+const SESSION_PREFIX: &str = "session-";
+// Without a way to tell whether a process is still running,
+// a session directory is stale once it hasn't changed for this long
+#[cfg(not(unix))]
+const STALE_AFTER: Duration = Duration::from_secs(24 * 60 * 60);
+
+static DIR: OnceLock<PathBuf> = OnceLock::new();
+// The process groups of the exercise binaries running right now
+static PROCESS_GROUPS: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+/// The private directory of this run of rustlings, where exercises are
+/// built into. It's created on first use and removed when rustlings exits,
+/// is interrupted or panics.
+pub fn dir() -> &'static Path {
+    DIR.get_or_init(|| {
+        let dir = env::current_dir()
+            .unwrap_or_default()
+            .join(SESSIONS_DIR)
+            .join(format!("{SESSION_PREFIX}{}", process::id()));
+        fs::create_dir_all(&dir).expect("Failed to create the session directory");
+        dir
+    })
+}
+
+/// Remove the session directories left behind by runs that crashed,
+/// and make sure the directory of this run is removed however it ends.
+pub fn init() {
+    let _ = sweep(Path::new(SESSIONS_DIR));
+
+    // Only a panic on the main thread ends rustlings,
+    // the worker threads' panics are handled by joining them
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        default_hook(info);
+        if thread::current().name() == Some("main") {
+            cleanup();
+        }
+    }));
+
+    #[cfg(unix)]
+    unix::clean_up_on_exit();
+}
+
+/// Remember the process group of a running exercise binary, so that it's
+/// killed with everything it spawned if rustlings is interrupted
+pub fn track_process_group(pgid: u32) {
+    process_groups().push(pgid);
+}
+
+/// Forget the process group of an exercise binary that was stopped
+pub fn untrack_process_group(pgid: u32) {
+    process_groups().retain(|&tracked| tracked != pgid);
+}
+
+fn process_groups() -> std::sync::MutexGuard<'static, Vec<u32>> {
+    PROCESS_GROUPS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Kill the exercise binaries that are still running
+/// and remove the directory of this run, if it got created
+pub fn cleanup() {
+    #[cfg(unix)]
+    for pgid in process_groups().drain(..) {
+        if let Ok(pgid) = libc::pid_t::try_from(pgid) {
+            unsafe { libc::kill(-pgid, libc::SIGKILL) };
+        }
+    }
+    if let Some(dir) = DIR.get() {
+        let _ = fs::remove_dir_all(dir);
+    }
+}
+
+// Remove the session directories of processes that aren't running anymore
+fn sweep(sessions: &Path) -> io::Result<()> {
+    for entry in fs::read_dir(sessions)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let pid = name
+            .to_str()
+            .and_then(|name| name.strip_prefix(SESSION_PREFIX))
+            .and_then(|pid| pid.parse::<u32>().ok());
+        match pid {
+            Some(pid) if pid != process::id() && !is_running(pid, &entry.path()) => {
+                let _ = fs::remove_dir_all(entry.path());
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn is_running(pid: u32, _dir: &Path) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // Signal 0 only checks whether the process exists.
+    // EPERM means it does, but belongs to someone else.
+    let exists = unsafe { libc::kill(pid, 0) } == 0;
+    exists || io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(not(unix))]
+fn is_running(_pid: u32, dir: &Path) -> bool {
+    let modified = fs::metadata(dir).and_then(|metadata| metadata.modified());
+    modified.is_ok_and(|modified| {
+        SystemTime::now()
+            .duration_since(modified)
+            .is_ok_and(|age| age < STALE_AFTER)
+    })
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::sync::atomic::{AtomicI32, Ordering};
+    use std::thread;
+
+    // The end of the pipe the signal handler writes the signal to
+    static SIGNAL_PIPE: AtomicI32 = AtomicI32::new(-1);
+
+    extern "C" fn on_exit() {
+        super::cleanup();
+    }
+
+    // Only async-signal-safe functions may be called here,
+    // so the cleanup happens on the thread reading the pipe
+    extern "C" fn on_signal(signal: libc::c_int) {
+        let byte = signal as u8;
+        unsafe {
+            libc::write(
+                SIGNAL_PIPE.load(Ordering::SeqCst),
+                (&byte as *const u8).cast(),
+                1,
+            );
+        }
+    }
+
+    pub fn clean_up_on_exit() {
+        // Runs on `process::exit` and when main returns
+        unsafe { libc::atexit(on_exit) };
+
+        let mut fds = [0; 2];
+        if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+            return;
+        }
+        let [read_end, write_end] = fds;
+        SIGNAL_PIPE.store(write_end, Ordering::SeqCst);
+        thread::spawn(move || {
+            let mut byte = 0u8;
+            if unsafe { libc::read(read_end, (&mut byte as *mut u8).cast(), 1) } != 1 {
+                return;
+            }
+            super::cleanup();
+            // Die of the signal like we would have without the handler
+            let signal = libc::c_int::from(byte);
+            unsafe {
+                libc::signal(signal, libc::SIG_DFL);
+                libc::raise(signal);
+            }
+        });
+
+        for signal in [libc::SIGINT, libc::SIGTERM] {
+            unsafe { libc::signal(signal, on_signal as *const () as libc::sighandler_t) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sweep_stale_sessions() {
+        let sessions = env::temp_dir().join(format!("rustlings-sweep-{}", process::id()));
+        // No process has the largest pid, it's above the kernel's limit
+        let stale = sessions.join(format!("{SESSION_PREFIX}{}", i32::MAX));
+        let running = sessions.join(format!("{SESSION_PREFIX}{}", process::id()));
+        let other = sessions.join("cache");
+        for dir in [&stale, &running, &other] {
+            fs::create_dir_all(dir).unwrap();
+        }
+
+        sweep(&sessions).unwrap();
+        let stale_exists = stale.exists();
+        let running_exists = running.exists();
+        let other_exists = other.exists();
+        fs::remove_dir_all(&sessions).unwrap();
+
+        assert_eq!(stale_exists, !cfg!(unix));
+        assert!(running_exists);
+        assert!(other_exists);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::diff::{self, Change};
+use crate::exercise::{self, Exercise};
+use crate::pristine;
+use console::style;
+use std::borrow::Cow;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Print the reference solution of the exercise,
+/// or how the learner's code differs from it.
+/// Fails with a message if there is no solution or it can't be read.
+pub fn show(exercise: &Exercise, diff: bool) -> Result<(), String> {
+    let solution = exercise
+        .solution_path()
+        .ok_or_else(|| format!("{} has no reference solution yet", exercise.name))?;
+    let files = files(exercise)
+        .map_err(|e| format!("Failed to read the solution {}: {e}", solution.display()))?;
+    if files.is_empty() {
+        return Err(format!("{} has no reference solution yet", exercise.name));
+    }
+
+    for (solution_file, content) in &files {
+        let text = String::from_utf8_lossy(content);
+        if files.len() > 1 {
+            println!("{}", style(solution_file.display()).blue().bold());
+        }
+        if !diff {
+            print!("{text}");
+            continue;
+        }
+        // A file the learner doesn't have shows up as all added
+        let exercise_file = counterpart(exercise, &solution, solution_file);
+        let yours = fs::read_to_string(exercise_file).unwrap_or_default();
+        let yours: Vec<&str> = yours.lines().collect();
+        let changes = diff::lines(&yours, &text.lines().collect::<Vec<_>>());
+        if changes
+            .iter()
+            .all(|change| matches!(change, Change::Same(_)))
+        {
+            println!("Your code is the same as the solution.");
+        } else {
+            print!("{}", diff::render(("yours", "solution"), &changes));
+        }
+    }
+    Ok(())
+}
+
+/// The files of the exercise's reference solution with their content, empty
+/// if it has none. They come from the copy shipped with rustlings or the pack,
+/// or else from the solutions directory of the tree, for exercise authors.
+pub fn files(exercise: &Exercise) -> io::Result<Vec<(PathBuf, Cow<'static, [u8]>)>> {
+    let shipped = pristine::solution_files(exercise);
+    if !shipped.is_empty() {
+        return Ok(shipped);
+    }
+    let Some(solution) = exercise.solution_path().filter(|path| path.exists()) else {
+        return Ok(Vec::new());
+    };
+    let paths = if solution.is_dir() {
+        exercise::crate_files(&solution)?
+    } else {
+        vec![solution]
+    };
+    paths
+        .into_iter()
+        .map(|path| {
+            let content = fs::read(&path)?;
+            Ok((path, Cow::Owned(content)))
+        })
+        .collect()
+}
+
+// The file of the exercise a file of its solution corresponds to
+fn counterpart(exercise: &Exercise, solution: &Path, file: &Path) -> PathBuf {
+    let without_cur_dir = |path: &Path| path.strip_prefix(".").unwrap_or(path).to_path_buf();
+    match without_cur_dir(file).strip_prefix(without_cur_dir(solution)) {
+        Ok(relative) if !relative.as_os_str().is_empty() => exercise.path.join(relative),
+        _ => exercise.path.clone(),
+    }
+}
@@ -1,8 +1,8 @@
 macro_rules! warn {
-    ($fmt:literal, $ex:expr) => {{
+    ($fmt:literal, $($ex:expr),+) => {{
         use console::{style, Emoji};
         use std::env;
-        let formatstr = format!($fmt, $ex);
+        let formatstr = format!($fmt, $($ex),+);
         if env::var("NO_EMOJI").is_ok() {
             println!("{} {}", style("!").red(), style(formatstr).red());
         } else {
@@ -15,11 +15,30 @@ macro_rules! warn {
     }};
 }
 
+// Like warn!, but on stderr, for warnings that can come up while
+// the output is JSON, which has stdout to itself
+macro_rules! ewarn {
+    ($fmt:literal, $($ex:expr),+) => {{
+        use console::{style, Emoji};
+        use std::env;
+        let formatstr = format!($fmt, $($ex),+);
+        if env::var("NO_EMOJI").is_ok() {
+            eprintln!("{} {}", style("!").red(), style(formatstr).red());
+        } else {
+            eprintln!(
+                "{} {}",
+                style(Emoji("⚠️ ", "!")).red(),
+                style(formatstr).red()
+            );
+        }
+    }};
+}
+
 macro_rules! success {
-    ($fmt:literal, $ex:expr) => {{
+    ($fmt:literal, $($ex:expr),+) => {{
         use console::{style, Emoji};
         use std::env;
-        let formatstr = format!($fmt, $ex);
+        let formatstr = format!($fmt, $($ex),+);
         if env::var("NO_EMOJI").is_ok() {
             println!("{} {}", style("✓").green(), style(formatstr).green());
         } else {
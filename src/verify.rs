@@ -1,43 +1,154 @@
-use crate::exercise::{CompiledExercise, Exercise, Mode, State};
+use crate::cache;
+use crate::diagnostics;
+use crate::exercise::{Exercise, ExerciseOutput, Limit, Mode, State, StdinCase};
+use crate::junit::{self, CaseResult};
+use crate::output::{self, Event, Format};
+use crate::progress::Progress;
+use crate::solution;
 use console::style;
-use indicatif::{ProgressBar, ProgressStyle};
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressFinish, ProgressStyle};
+use serde::{Deserialize, Serialize};
 use std::env;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
 
 // Verify that the provided container of Exercise objects
 // can be compiled and run without any failures.
 // Any such failures will be reported to the end user.
 // If the Exercise being verified is a test, the verbose boolean
 // determines whether or not the test harness outputs are displayed.
+// Every exercise that compiles and passes is recorded in the progress store.
+// With more than one job, exercises are compiled and run concurrently,
+// but their results are still reported in the given order.
+// The result of every exercise is also recorded in the JUnit report, if any.
 pub fn verify<'a>(
     exercises: impl IntoIterator<Item = &'a Exercise>,
     progress: (usize, usize),
-    verbose: bool,
-    success_hints: bool,
-) -> Result<(), &'a Exercise> {
+    store: &mut Progress,
+    options: &Options,
+    mut junit: Option<&mut junit::Report>,
+) -> Result<(), (&'a Exercise, Failure)> {
+    let format = options.format;
     let (num_done, total) = progress;
-    let bar = ProgressBar::new(total as u64);
+    // The overall bar and the spinners of the exercises being checked.
+    // The bar stays on screen where it got to once verification is over.
+    let multi = MultiProgress::new();
+    if format == Format::Json {
+        multi.set_draw_target(ProgressDrawTarget::hidden());
+    }
+    let bar = multi.add(ProgressBar::new(total as u64).with_finish(ProgressFinish::Abandon));
     let mut percentage = num_done as f32 / total as f32 * 100.0;
-    bar.set_style(ProgressStyle::default_bar()
-        .template("Progress: [{bar:60.green/red}] {pos}/{len} {msg}")
-        .progress_chars("#>-")
+    bar.set_style(
+        ProgressStyle::default_bar()
+            .template("Progress: [{bar:60.green/red}] {pos}/{len} {msg}")
+            .expect("The template of the progress bar is valid")
+            .progress_chars("#>-"),
     );
     bar.set_position(num_done as u64);
     bar.set_message(format!("({:.1} %)", percentage));
 
-    for exercise in exercises {
-        let compile_result = match exercise.mode {
-            Mode::Test => compile_and_test(exercise, RunMode::Interactive, verbose, success_hints),
-            Mode::Compile => compile_and_run_interactively(exercise, success_hints),
-            Mode::Clippy => compile_only(exercise, success_hints),
-        };
-        if !compile_result.unwrap_or(false) {
-            return Err(exercise);
+    let mut first_failure = None;
+    let mut report_checked = |exercise: &'a Exercise, checked: Checked| {
+        if let Some(junit) = junit.as_deref_mut() {
+            let (time, result) = junit_case(exercise, &checked);
+            junit.record(exercise, time, result);
+        }
+        // The display is out of the way while the outcome is printed
+        let failure = multi.suspend(|| {
+            match report(
+                exercise,
+                checked,
+                RunMode::Interactive,
+                options.verbose,
+                options.success_hints,
+                format,
+            ) {
+                Ok(done) => {
+                    let was_done = store.is_done(exercise);
+                    store.record(exercise);
+                    if options.offer_solutions && !was_done && store.is_done(exercise) {
+                        offer_solution(exercise);
+                    }
+                    (!done).then_some(Failure::Pending)
+                }
+                Err(failure) => Some(failure),
+            }
+        });
+        match failure {
+            Some(failure) if options.keep_going => {
+                first_failure.get_or_insert((exercise, failure));
+            }
+            Some(failure) => return Err((exercise, failure)),
+            None => {
+                percentage += 100.0 / total as f32;
+                bar.inc(1);
+                bar.set_message(format!("({:.1} %)", percentage));
+            }
+        }
+        Ok(())
+    };
+
+    let exercises: Vec<&'a Exercise> = exercises.into_iter().collect();
+    if options.jobs > 1 {
+        check_parallel(&exercises, options.jobs, format, &multi, report_checked)?;
+    } else {
+        for exercise in exercises {
+            let progress_bar = multi.add(spinner(format));
+            let checked = check(exercise, &progress_bar);
+            progress_bar.finish_and_clear();
+            report_checked(exercise, checked)?;
+        }
+    }
+    first_failure.map_or(Ok(()), Err)
+}
+
+// How `verify` checks exercises and reports them
+pub struct Options {
+    // Whether to show the output of passing test harnesses
+    pub verbose: bool,
+    // Whether to show the hint of an exercise once it compiles
+    pub success_hints: bool,
+    // Whether to point out the reference solution of an exercise once it's done
+    pub offer_solutions: bool,
+    // How many exercises to check in parallel
+    pub jobs: usize,
+    pub format: Format,
+    // Whether to carry on past exercises that fail or are still pending.
+    // Verification then fails with the first of them.
+    pub keep_going: bool,
+}
+
+// Why an exercise didn't pass, each one with its own exit code.
+// The exit codes are documented in the help of the `rustlings` command.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Failure {
+    // The exercise didn't compile, or clippy wasn't happy with it
+    CompileFailed,
+    // The binary or test harness exited with an error
+    RunFailed,
+    // The exercise passed but still has its `I AM NOT DONE` marker
+    Pending,
+    // The binary or test harness was stopped by the runner
+    LimitExceeded,
+    // The binary didn't print the expected output
+    OutputMismatch,
+    // A compile_fail exercise compiled, or failed with other errors
+    WrongErrors,
+}
+
+impl Failure {
+    pub fn exit_code(self) -> i32 {
+        match self {
+            Failure::CompileFailed => 2,
+            Failure::RunFailed => 3,
+            Failure::Pending => 4,
+            Failure::LimitExceeded => 5,
+            Failure::OutputMismatch => 6,
+            Failure::WrongErrors => 7,
         }
-        percentage += 100.0 / total as f32;
-        bar.inc(1);
-        bar.set_message(format!("({:.1} %)", percentage));
     }
-    Ok(())
 }
 
 enum RunMode {
@@ -45,64 +156,289 @@ enum RunMode {
     NonInteractive,
 }
 
-// Compile and run the resulting test harness of the given Exercise
-pub fn test(exercise: &Exercise, verbose: bool) -> Result<(), ()> {
-    compile_and_test(exercise, RunMode::NonInteractive, verbose, false)?;
-    Ok(())
+// The outcome of compiling and running a single exercise,
+// kept around so that it can be reported later on
+#[derive(Serialize, Deserialize)]
+enum Outcome {
+    // The exercise didn't compile, or clippy wasn't happy with it
+    CompileFailed(ExerciseOutput),
+    // The binary or test harness exited with an error
+    RunFailed(ExerciseOutput),
+    // The binary or test harness was stopped by the runner
+    LimitExceeded(Limit, ExerciseOutput),
+    // The binary ran successfully but didn't print the expected output,
+    // along with a coloured diff of the two
+    OutputMismatch(ExerciseOutput, String),
+    // The binary was run with each of the exercise's stdin fixtures
+    Cases(Vec<CaseOutcome>),
+    // A compile_fail exercise compiled, or failed with other errors than
+    // the expected ones, along with the errors it failed with
+    WrongErrors(ExerciseOutput, Vec<String>),
+    // The exercise compiled and ran successfully.
+    // Clippy exercises are only compiled, so they have no output.
+    Passed(Option<ExerciseOutput>),
 }
 
-// Invoke the rust compiler without running the resulting binary
-fn compile_only(exercise: &Exercise, success_hints: bool) -> Result<bool, ()> {
-    let progress_bar = ProgressBar::new_spinner();
-    progress_bar.set_message(format!("Compiling {exercise}..."));
-    progress_bar.enable_steady_tick(100);
+impl Outcome {
+    // Whether the exercise compiled and ran as expected
+    fn passed(&self) -> bool {
+        match self {
+            Outcome::Passed(_) => true,
+            Outcome::Cases(cases) => cases.iter().all(|case| case.outcome.passed()),
+            _ => false,
+        }
+    }
+}
 
-    let _ = compile(exercise, &progress_bar)?;
-    progress_bar.finish_and_clear();
+// The outcome of running the binary with one of the stdin fixtures
+#[derive(Serialize, Deserialize)]
+struct CaseOutcome {
+    name: String,
+    outcome: Outcome,
+    run_time: Duration,
+}
 
-    Ok(prompt_for_completion(exercise, None, success_hints))
+// An outcome along with how long it took to get there
+#[derive(Serialize, Deserialize)]
+struct Checked {
+    outcome: Outcome,
+    compile_time: Duration,
+    // Only set if the exercise got to run
+    run_time: Option<Duration>,
+    // Whether this was loaded from the cache
+    #[serde(skip)]
+    cached: bool,
 }
 
-// Compile the given Exercise and run the resulting binary in an interactive mode
-fn compile_and_run_interactively(exercise: &Exercise, success_hints: bool) -> Result<bool, ()> {
+// Compile and run a single Exercise without prompting, as `rustlings run` does.
+// Test harnesses are always run through here, other modes only with JSON output.
+pub fn verify_single(exercise: &Exercise, verbose: bool, format: Format) -> Result<(), Failure> {
+    let progress_bar = spinner(format);
+    let checked = check(exercise, &progress_bar);
+    progress_bar.finish_and_clear();
+    report(
+        exercise,
+        checked,
+        RunMode::NonInteractive,
+        verbose,
+        false,
+        format,
+    )?;
+    Ok(())
+}
+
+fn spinner(format: Format) -> ProgressBar {
+    if format == Format::Json {
+        return ProgressBar::hidden();
+    }
     let progress_bar = ProgressBar::new_spinner();
-    progress_bar.set_message(format!("Compiling {exercise}..."));
-    progress_bar.enable_steady_tick(100);
+    progress_bar.enable_steady_tick(Duration::from_millis(100));
+    progress_bar
+}
 
-    let compilation = compile(exercise, &progress_bar)?;
+// Compile the given Exercise and run the resulting binary or test harness,
+// without reporting anything to the end user yet.
+// Outcomes are cached, so an unchanged exercise isn't compiled again.
+fn check(exercise: &Exercise, progress_bar: &ProgressBar) -> Checked {
+    let key = cache::key(exercise);
+    if let Some(checked) = key.as_deref().and_then(cache::load::<Checked>) {
+        return Checked {
+            cached: true,
+            ..checked
+        };
+    }
 
-    progress_bar.set_message(format!("Running {exercise}..."));
-    let result = compilation.run();
-    progress_bar.finish_and_clear();
+    let checked = check_uncached(exercise, progress_bar);
+    // Hitting a limit can depend on the load of the machine, so try again next time
+    if let (Some(key), false) = (key, matches!(checked.outcome, Outcome::LimitExceeded(..))) {
+        cache::store(&key, &checked);
+    }
+    checked
+}
 
-    let output = match result {
-        Ok(output) => output,
-        Err(output) => {
-            warn!("Ran {} with errors", exercise);
-            println!("{}", output.stdout);
-            println!("{}", output.stderr);
-            return Err(());
+fn check_uncached(exercise: &Exercise, progress_bar: &ProgressBar) -> Checked {
+    match exercise.mode {
+        Mode::Test | Mode::Doctest | Mode::Cargo => {
+            progress_bar.set_message(format!("Testing {exercise}..."))
         }
+        _ => progress_bar.set_message(format!("Compiling {exercise}...")),
+    }
+
+    let start = Instant::now();
+    let compiled = exercise.compile();
+    let compile_time = start.elapsed();
+    let checked = |outcome, run_time| Checked {
+        outcome,
+        compile_time,
+        run_time,
+        cached: false,
     };
 
-    Ok(prompt_for_completion(exercise, Some(output.stdout), success_hints))
-}
+    if let Mode::CompileFail = exercise.mode {
+        return checked(compile_fail_outcome(exercise, compiled.err()), None);
+    }
+    let compilation = match compiled {
+        Ok(compilation) => compilation,
+        Err(output) => return checked(Outcome::CompileFailed(output), None),
+    };
 
-// Compile the given Exercise as a test harness and display
-// the output if verbose is set to true
-fn compile_and_test(exercise: &Exercise, run_mode: RunMode, verbose: bool, success_hints: bool) -> Result<bool, ()> {
-    let progress_bar = ProgressBar::new_spinner();
-    progress_bar.set_message(format!("Testing {exercise}..."));
-    progress_bar.enable_steady_tick(100);
+    match exercise.mode {
+        Mode::Clippy | Mode::CompileFail => return checked(Outcome::Passed(None), None),
+        Mode::Compile => progress_bar.set_message(format!("Running {exercise}...")),
+        Mode::Test | Mode::Doctest | Mode::Cargo => {}
+    }
 
-    let compilation = compile(exercise, &progress_bar)?;
-    let result = compilation.run();
-    progress_bar.finish_and_clear();
+    let start = Instant::now();
+    if let (Mode::Compile, false) = (exercise.mode, exercise.stdin.is_empty()) {
+        let cases = exercise
+            .stdin
+            .iter()
+            .enumerate()
+            .map(|(i, case)| {
+                let start = Instant::now();
+                let outcome = match case.input() {
+                    Ok(input) => {
+                        run_outcome(exercise, Some(case), compilation.run_with_input(&input))
+                    }
+                    Err(e) => Outcome::RunFailed(ExerciseOutput {
+                        stdout: String::new(),
+                        stderr: format!("Failed to read the input: {e}"),
+                        diagnostics: Vec::new(),
+                        limit_exceeded: None,
+                    }),
+                };
+                CaseOutcome {
+                    name: case.name.clone().unwrap_or_else(|| format!("#{}", i + 1)),
+                    outcome,
+                    run_time: start.elapsed(),
+                }
+            })
+            .collect();
+        return checked(Outcome::Cases(cases), Some(start.elapsed()));
+    }
+    let outcome = run_outcome(exercise, None, compilation.run());
+    checked(outcome, Some(start.elapsed()))
+}
+
+// A compile_fail exercise passes if it failed to compile
+// with exactly the expected errors, and no others
+fn compile_fail_outcome(exercise: &Exercise, failed: Option<ExerciseOutput>) -> Outcome {
+    let output = failed.unwrap_or(ExerciseOutput {
+        stdout: String::new(),
+        stderr: String::new(),
+        diagnostics: Vec::new(),
+        limit_exceeded: None,
+    });
+    let errors = diagnostics::error_codes(&output.diagnostics);
+    let mut expected = exercise.expected_errors.clone();
+    expected.sort();
+    expected.dedup();
+    if errors.is_empty() || errors != expected {
+        Outcome::WrongErrors(output, errors)
+    } else {
+        Outcome::Passed(Some(output))
+    }
+}
 
+// The outcome of running the binary or test harness,
+// optionally with one of the exercise's stdin fixtures
+fn run_outcome(
+    exercise: &Exercise,
+    case: Option<&StdinCase>,
+    result: Result<ExerciseOutput, ExerciseOutput>,
+) -> Outcome {
     match result {
-        Ok(output) => {
+        Ok(output) => match exercise.output_diff(case, &output.stdout) {
+            Some(diff) => Outcome::OutputMismatch(output, diff),
+            None => Outcome::Passed(Some(output)),
+        },
+        Err(
+            output @ ExerciseOutput {
+                limit_exceeded: Some(limit),
+                ..
+            },
+        ) => Outcome::LimitExceeded(limit, output),
+        Err(output) => Outcome::RunFailed(output),
+    }
+}
+
+// Check the given exercises with a pool of `jobs` worker threads,
+// each one showing its current exercise in a spinner of the display.
+// The outcomes are reported in the order of the exercises as soon as they
+// and the ones before them are checked, until reporting one fails.
+fn check_parallel<'a, E>(
+    exercises: &[&'a Exercise],
+    jobs: usize,
+    format: Format,
+    multi: &MultiProgress,
+    mut report: impl FnMut(&'a Exercise, Checked) -> Result<(), E>,
+) -> Result<(), E> {
+    let next = AtomicUsize::new(0);
+    let stop = AtomicBool::new(false);
+    let (tx, rx) = mpsc::channel();
+    let mut results: Vec<Option<Checked>> = exercises.iter().map(|_| None).collect();
+
+    thread::scope(|s| {
+        for _ in 0..jobs.min(exercises.len()) {
+            let progress_bar = multi.add(spinner(format));
+            let (tx, next, stop) = (tx.clone(), &next, &stop);
+            s.spawn(move || {
+                while !stop.load(Ordering::SeqCst) {
+                    let index = next.fetch_add(1, Ordering::SeqCst);
+                    let Some(exercise) = exercises.get(index) else {
+                        break;
+                    };
+                    if tx.send((index, check(exercise, &progress_bar))).is_err() {
+                        break;
+                    }
+                }
+                progress_bar.finish_and_clear();
+            });
+        }
+        drop(tx);
+
+        let mut reported = Ok(());
+        let mut in_order = 0;
+        for (index, checked) in rx {
+            results[index] = Some(checked);
+            while let Some(checked) = results.get_mut(in_order).and_then(Option::take) {
+                reported = report(exercises[in_order], checked);
+                if reported.is_err() {
+                    stop.store(true, Ordering::SeqCst);
+                    break;
+                }
+                in_order += 1;
+            }
+            if reported.is_err() {
+                break;
+            }
+        }
+        reported
+    })
+}
+
+// Report the outcome of an exercise to the end user.
+// Returns whether the exercise can be considered done, or why it failed.
+fn report(
+    exercise: &Exercise,
+    checked: Checked,
+    run_mode: RunMode,
+    verbose: bool,
+    success_hints: bool,
+    format: Format,
+) -> Result<bool, Failure> {
+    if format == Format::Json {
+        return report_json(exercise, checked, run_mode);
+    }
+
+    let output = report_outcome(exercise, checked.outcome)?;
+
+    match exercise.mode {
+        Mode::Test | Mode::Doctest | Mode::Cargo => {
             if verbose {
-                println!("{}", output.stdout);
+                if let Some(output) = output {
+                    println!("{}", output.stdout);
+                }
             }
             if let RunMode::Interactive = run_mode {
                 Ok(prompt_for_completion(exercise, None, success_hints))
@@ -110,48 +446,375 @@ fn compile_and_test(exercise: &Exercise, run_mode: RunMode, verbose: bool, succe
                 Ok(true)
             }
         }
-        Err(output) => {
+        Mode::Compile => Ok(prompt_for_completion(
+            exercise,
+            output.map(|output| output.stdout),
+            success_hints,
+        )),
+        Mode::Clippy => Ok(prompt_for_completion(exercise, None, success_hints)),
+        Mode::CompileFail => {
+            if let RunMode::Interactive = run_mode {
+                Ok(prompt_for_completion(exercise, None, success_hints))
+            } else {
+                success!("{} failed to compile with the expected errors!", exercise);
+                Ok(true)
+            }
+        }
+    }
+}
+
+// Report a failed outcome to the end user,
+// or return the output of the binary or test harness if it passed
+fn report_outcome(
+    exercise: &Exercise,
+    outcome: Outcome,
+) -> Result<Option<ExerciseOutput>, Failure> {
+    match outcome {
+        Outcome::CompileFailed(output) => {
+            warn!(
+                "Compiling of {} failed with {}! Please try again. Here's the output:",
+                exercise,
+                diagnostics::summarize(&output.diagnostics)
+            );
+            print!("{}", diagnostics::render(&output.diagnostics));
+            println!("{}", output.stderr);
+            if let Mode::Clippy = exercise.mode {
+                println!("Checked with these lints: {}", exercise.lints());
+            }
+            Err(Failure::CompileFailed)
+        }
+        Outcome::RunFailed(output) => {
+            if let Mode::Test | Mode::Doctest | Mode::Cargo = exercise.mode {
+                warn!(
+                    "Testing of {} failed! Please try again. Here's the output:",
+                    exercise
+                );
+                println!("{}", output.stdout);
+            } else {
+                warn!("Ran {} with errors", exercise);
+                println!("{}", output.stdout);
+                println!("{}", output.stderr);
+            }
+            Err(Failure::RunFailed)
+        }
+        Outcome::LimitExceeded(limit, output) => {
             warn!(
-                "Testing of {} failed! Please try again. Here's the output:",
+                "{} was stopped because it exceeded {}! Here's the output so far:",
+                exercise, limit
+            );
+            println!("{}", output.stdout);
+            println!("{}", output.stderr);
+            Err(Failure::LimitExceeded)
+        }
+        Outcome::OutputMismatch(output, diff) => {
+            warn!(
+                "Ran {} but it didn't print the expected output! Please try again. Here's the output:",
                 exercise
             );
             println!("{}", output.stdout);
-            Err(())
+            println!("{}", output.stderr);
+            print!("{diff}");
+            Err(Failure::OutputMismatch)
+        }
+        Outcome::WrongErrors(output, errors) => {
+            let expected = exercise.expected_errors.join(", ");
+            if errors.is_empty() {
+                warn!(
+                    "{} compiled, but it should fail to compile with {}! Please try again.",
+                    exercise, expected
+                );
+            } else {
+                warn!(
+                    "{} failed to compile with {} instead of {}! Please try again. Here's the output:",
+                    exercise,
+                    errors.join(", "),
+                    expected
+                );
+            }
+            print!("{}", diagnostics::render(&output.diagnostics));
+            println!("{}", output.stderr);
+            Err(Failure::WrongErrors)
         }
+        Outcome::Cases(cases) => {
+            let mut first_failure = None;
+            for case in cases {
+                if case.outcome.passed() {
+                    success!("Ran {} with input {}", exercise, case.name);
+                    continue;
+                }
+                warn!("With input {}:", case.name);
+                if let Err(failure) = report_outcome(exercise, case.outcome) {
+                    first_failure.get_or_insert(failure);
+                }
+            }
+            first_failure.map_or(Ok(None), Err)
+        }
+        Outcome::Passed(output) => Ok(output),
     }
 }
 
-// Compile the given Exercise and return an object with information
-// about the state of the compilation
-fn compile<'a, 'b>(
-    exercise: &'a Exercise,
-    progress_bar: &'b ProgressBar,
-) -> Result<CompiledExercise<'a>, ()> {
-    let compilation_result = exercise.compile();
+// Report the outcome of an exercise as JSON events, one per line
+fn report_json(exercise: &Exercise, checked: Checked, run_mode: RunMode) -> Result<bool, Failure> {
+    let name = exercise.name.as_str();
+    let cached = checked.cached;
+    output::emit(&Event::Started {
+        exercise: name,
+        path: &exercise.path,
+        mode: exercise.mode,
+    });
 
-    match compilation_result {
-        Ok(compilation) => Ok(compilation),
-        Err(output) => {
-            progress_bar.finish_and_clear();
-            warn!(
-                "Compiling of {} failed! Please try again. Here's the output:",
-                exercise
-            );
-            println!("{}", output.stderr);
-            Err(())
+    if let Outcome::CompileFailed(output) = &checked.outcome {
+        let summary = diagnostics::summarize(&output.diagnostics);
+        let rendered = diagnostics::render(&output.diagnostics) + &output.stderr;
+        output::emit(&Event::CompileFailed {
+            exercise: name,
+            duration_ms: output::millis(checked.compile_time),
+            cached,
+            errors: summary.errors,
+            warnings: summary.warnings,
+            output: plain(&rendered),
+            diagnostics: &output.diagnostics,
+        });
+        return Err(Failure::CompileFailed);
+    }
+    if let Mode::CompileFail = exercise.mode {
+        emit_compile_fail(exercise, &checked)?;
+        return Ok(pending_marker(exercise, run_mode));
+    }
+    output::emit(&Event::Compiled {
+        exercise: name,
+        duration_ms: output::millis(checked.compile_time),
+        cached,
+    });
+
+    let run_time = checked.run_time.unwrap_or_default();
+    if let Outcome::Cases(cases) = &checked.outcome {
+        let mut first_failure = None;
+        for case in cases {
+            let result = emit_run(name, Some(&case.name), &case.outcome, case.run_time, cached);
+            if let Err(failure) = result {
+                first_failure.get_or_insert(failure);
+            }
+        }
+        if let Some(failure) = first_failure {
+            return Err(failure);
         }
+    } else {
+        emit_run(name, None, &checked.outcome, run_time, cached)?;
+    }
+    Ok(pending_marker(exercise, run_mode))
+}
+
+// Emit the event for the `I AM NOT DONE` marker of an exercise that passed,
+// if it still has one and verification would stop there
+fn pending_marker(exercise: &Exercise, run_mode: RunMode) -> bool {
+    if let (RunMode::Interactive, State::Pending(context)) = (run_mode, exercise.state()) {
+        let line = context
+            .iter()
+            .find(|context_line| context_line.important)
+            .map_or(0, |context_line| context_line.number);
+        output::emit(&Event::PendingMarker {
+            exercise: &exercise.name,
+            line,
+        });
+        return false;
     }
+    true
 }
 
-fn prompt_for_completion(exercise: &Exercise, prompt_output: Option<String>, success_hints: bool) -> bool {
+// Emit the event for a compile_fail exercise, which doesn't get to run.
+// Passing means failing to compile, so the errors end up on stderr.
+fn emit_compile_fail(exercise: &Exercise, checked: &Checked) -> Result<(), Failure> {
+    let duration_ms = output::millis(checked.compile_time);
+    match &checked.outcome {
+        Outcome::WrongErrors(output, errors) => {
+            output::emit(&Event::WrongErrors {
+                exercise: &exercise.name,
+                duration_ms,
+                cached: checked.cached,
+                expected: &exercise.expected_errors,
+                errors,
+                output: plain(&(diagnostics::render(&output.diagnostics) + &output.stderr)),
+            });
+            Err(Failure::WrongErrors)
+        }
+        outcome => {
+            let stderr = match outcome {
+                Outcome::Passed(Some(output)) => plain(&diagnostics::render(&output.diagnostics)),
+                _ => String::new(),
+            };
+            output::emit(&Event::Passed {
+                exercise: &exercise.name,
+                case: None,
+                duration_ms,
+                cached: checked.cached,
+                stdout: "",
+                stderr: &stderr,
+            });
+            Ok(())
+        }
+    }
+}
+
+// Emit the event for a run of the binary or test harness of a compiled exercise,
+// optionally with one of its stdin fixtures
+fn emit_run(
+    exercise: &str,
+    case: Option<&str>,
+    outcome: &Outcome,
+    run_time: Duration,
+    cached: bool,
+) -> Result<(), Failure> {
+    let (output, failure) = match outcome {
+        Outcome::RunFailed(output) => (Some(output), Some((Failure::RunFailed, None, None))),
+        Outcome::LimitExceeded(limit, output) => (
+            Some(output),
+            Some((Failure::LimitExceeded, Some(limit.to_string()), None)),
+        ),
+        Outcome::OutputMismatch(output, diff) => (
+            Some(output),
+            Some((Failure::OutputMismatch, None, Some(plain(diff)))),
+        ),
+        Outcome::Passed(output) => (output.as_ref(), None),
+        // Reported before the exercise gets to run
+        Outcome::CompileFailed(_) | Outcome::WrongErrors(..) | Outcome::Cases(_) => return Ok(()),
+    };
+    let (stdout, stderr) = output.map_or(("", ""), |output| {
+        (output.stdout.as_str(), output.stderr.as_str())
+    });
+    let duration_ms = output::millis(run_time);
+
+    if let Some((failure, limit_exceeded, diff)) = failure {
+        output::emit(&Event::TestFailed {
+            exercise,
+            case,
+            duration_ms,
+            cached,
+            limit_exceeded,
+            diff,
+            stdout,
+            stderr,
+        });
+        return Err(failure);
+    }
+    output::emit(&Event::Passed {
+        exercise,
+        case,
+        duration_ms,
+        cached,
+        stdout,
+        stderr,
+    });
+    Ok(())
+}
+
+// How the outcome of an exercise ends up in a JUnit report
+fn junit_case(exercise: &Exercise, checked: &Checked) -> (Duration, CaseResult) {
+    let time = checked.compile_time + checked.run_time.unwrap_or_default();
+    let result = match junit_result(exercise, &checked.outcome) {
+        CaseResult::Passed if !exercise.looks_done() => CaseResult::Skipped {
+            message: "Still has its `I AM NOT DONE` marker".to_string(),
+        },
+        result => result,
+    };
+    (time, result)
+}
+
+fn junit_result(exercise: &Exercise, outcome: &Outcome) -> CaseResult {
+    match outcome {
+        Outcome::CompileFailed(output) => CaseResult::Failed {
+            kind: "compile_failed",
+            message: format!(
+                "Compiling failed with {}",
+                diagnostics::summarize(&output.diagnostics)
+            ),
+            output: plain(&(diagnostics::render(&output.diagnostics) + &output.stderr)),
+        },
+        Outcome::RunFailed(output) => CaseResult::Failed {
+            kind: "test_failed",
+            message: match exercise.mode {
+                Mode::Test | Mode::Doctest | Mode::Cargo => "Testing failed".to_string(),
+                _ => "Ran with errors".to_string(),
+            },
+            output: plain(&(output.stdout.clone() + &output.stderr)),
+        },
+        Outcome::LimitExceeded(limit, output) => CaseResult::Failed {
+            kind: "limit_exceeded",
+            message: format!("Stopped because it exceeded {limit}"),
+            output: plain(&(output.stdout.clone() + &output.stderr)),
+        },
+        Outcome::OutputMismatch(_, diff) => CaseResult::Failed {
+            kind: "output_mismatch",
+            message: "Didn't print the expected output".to_string(),
+            output: plain(diff),
+        },
+        Outcome::WrongErrors(output, errors) => CaseResult::Failed {
+            kind: "wrong_errors",
+            message: if errors.is_empty() {
+                format!(
+                    "Compiled, but should fail with {}",
+                    exercise.expected_errors.join(", ")
+                )
+            } else {
+                format!(
+                    "Failed with {} instead of {}",
+                    errors.join(", "),
+                    exercise.expected_errors.join(", ")
+                )
+            },
+            output: plain(&(diagnostics::render(&output.diagnostics) + &output.stderr)),
+        },
+        // An exercise fails with its first failing stdin fixture
+        Outcome::Cases(cases) => cases
+            .iter()
+            .find_map(|case| match junit_result(exercise, &case.outcome) {
+                CaseResult::Failed {
+                    kind,
+                    message,
+                    output,
+                } => Some(CaseResult::Failed {
+                    kind,
+                    message: format!("With input {}: {message}", case.name),
+                    output,
+                }),
+                _ => None,
+            })
+            .unwrap_or(CaseResult::Passed),
+        Outcome::Passed(_) => CaseResult::Passed,
+    }
+}
+
+fn offer_solution(exercise: &Exercise) {
+    if !solution::files(exercise).is_ok_and(|files| !files.is_empty()) {
+        return;
+    }
+    success!("Done with {}!", exercise);
+    println!(
+        "See how it compares to the reference solution with `rustlings solution {} --diff`",
+        exercise.name
+    );
+    println!();
+}
+
+// Compiler and program output without colors, for machines to read
+fn plain(text: &str) -> String {
+    console::strip_ansi_codes(text).into_owned()
+}
+
+fn prompt_for_completion(
+    exercise: &Exercise,
+    prompt_output: Option<String>,
+    success_hints: bool,
+) -> bool {
     let context = match exercise.state() {
         State::Done => return true,
         State::Pending(context) => context,
     };
     match exercise.mode {
         Mode::Compile => success!("Successfully ran {}!", exercise),
-        Mode::Test => success!("Successfully tested {}!", exercise),
+        Mode::Test | Mode::Doctest | Mode::Cargo => success!("Successfully tested {}!", exercise),
         Mode::Clippy => success!("Successfully compiled {}!", exercise),
+        Mode::CompileFail => success!("{} failed to compile with the expected errors!", exercise),
     }
 
     let no_emoji = env::var("NO_EMOJI").is_ok();
@@ -164,8 +827,9 @@ fn prompt_for_completion(exercise: &Exercise, prompt_output: Option<String>, suc
 
     let success_msg = match exercise.mode {
         Mode::Compile => "The code is compiling!",
-        Mode::Test => "The code is compiling, and the tests pass!",
+        Mode::Test | Mode::Doctest | Mode::Cargo => "The code is compiling, and the tests pass!",
         Mode::Clippy => clippy_success_msg,
+        Mode::CompileFail => "The code fails to compile for the right reason!",
     };
     println!();
     if no_emoji {
@@ -174,6 +838,10 @@ fn prompt_for_completion(exercise: &Exercise, prompt_output: Option<String>, suc
         println!("🎉 🎉  {success_msg} 🎉 🎉")
     }
     println!();
+    if let Mode::Clippy = exercise.mode {
+        println!("Checked with these lints: {}", exercise.lints());
+        println!();
+    }
 
     if let Some(output) = prompt_output {
         println!("Output:");
@@ -196,6 +864,9 @@ fn prompt_for_completion(exercise: &Exercise, prompt_output: Option<String>, suc
         style("`I AM NOT DONE`").bold()
     );
     println!();
+    if let (Mode::Cargo, Some(file)) = (exercise.mode, exercise.pending_file()) {
+        println!("{}", style(file.display()).blue().bold());
+    }
     for context_line in context {
         let formatted_line = if context_line.important {
             format!("{}", style(context_line.line).bold())
@@ -0,0 +1,99 @@
+use crate::pack::PACKS_DIR;
+use std::env;
+use std::path::{Path, PathBuf};
+
+// Overrides where the workspace is, unless `--workspace` is given
+const HOME_VAR: &str = "RUSTLINGS_HOME";
+
+/// Where rustlings was run in
+#[derive(PartialEq, Debug)]
+pub struct Workspace {
+    /// The directory with info.toml in it
+    pub root: PathBuf,
+    /// The installed pack rustlings was run in, if any
+    pub pack: Option<String>,
+}
+
+/// The workspace in the given directory, or the one in `RUSTLINGS_HOME`,
+/// or else the closest one up from the current directory.
+pub fn find(explicit: Option<&Path>) -> Result<Workspace, String> {
+    let explicit = explicit
+        .map(Path::to_path_buf)
+        .or_else(|| env::var_os(HOME_VAR).map(PathBuf::from));
+    if let Some(dir) = explicit {
+        return if dir.join("info.toml").is_file() {
+            Ok(in_dir(dir))
+        } else {
+            Err(format!(
+                "{} is not a rustlings workspace, it has no info.toml",
+                dir.display()
+            ))
+        };
+    }
+
+    let cwd =
+        env::current_dir().map_err(|e| format!("Failed to get the current directory: {e}"))?;
+    find_from(&cwd).ok_or_else(|| "Couldn't find a rustlings workspace".to_string())
+}
+
+// The closest workspace up from `dir`
+fn find_from(dir: &Path) -> Option<Workspace> {
+    dir.ancestors()
+        .find(|dir| dir.join("info.toml").is_file())
+        .map(|dir| in_dir(dir.to_path_buf()))
+}
+
+// The workspace of a directory with info.toml in it,
+// which is a pack when it's installed in the packs of another one
+fn in_dir(dir: PathBuf) -> Workspace {
+    let installed_in = dir
+        .parent()
+        .filter(|packs| packs.file_name().is_some_and(|name| name == PACKS_DIR))
+        .and_then(Path::parent)
+        .filter(|root| root.join("info.toml").is_file());
+    match (installed_in, dir.file_name()) {
+        (Some(root), Some(name)) => Workspace {
+            root: root.to_path_buf(),
+            pack: Some(name.to_string_lossy().into_owned()),
+        },
+        _ => Workspace {
+            root: dir,
+            pack: None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_find_from_subdirectory() {
+        let root = env::temp_dir().join(format!("rustlings_workspace_{}", std::process::id()));
+        let nested = root.join("exercises/intro");
+        let pack = root.join(PACKS_DIR).join("extra");
+        fs::create_dir_all(&nested).unwrap();
+        fs::create_dir_all(pack.join("exercises")).unwrap();
+        fs::write(root.join("info.toml"), "").unwrap();
+        fs::write(pack.join("info.toml"), "").unwrap();
+
+        let found = find_from(&nested);
+        let found_pack = find_from(&pack.join("exercises"));
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(
+            found,
+            Some(Workspace {
+                root: root.clone(),
+                pack: None
+            })
+        );
+        assert_eq!(
+            found_pack,
+            Some(Workspace {
+                root,
+                pack: Some("extra".to_string())
+            })
+        );
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    assert_eq!(cargo_failure::answer(), 42);
+}
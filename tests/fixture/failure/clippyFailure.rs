@@ -0,0 +1,6 @@
+fn main() {
+    let x: f64 = 0.1 + 0.2;
+    if x == 0.3 {
+        println!("equal");
+    }
+}
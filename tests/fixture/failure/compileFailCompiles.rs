@@ -0,0 +1,5 @@
+fn main() {
+    let greeting = String::from("Hello!");
+    let borrowed = &greeting;
+    println!("{greeting} {borrowed}");
+}
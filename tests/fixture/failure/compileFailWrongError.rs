@@ -0,0 +1,4 @@
+fn main() {
+    let greeting: i32 = "Hello!";
+    println!("{greeting}");
+}
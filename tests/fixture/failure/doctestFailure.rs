@@ -0,0 +1,17 @@
+/// Adds two numbers.
+///
+/// ```
+/// assert_eq!(doctestFailure::add(2, 3), 6);
+/// ```
+pub fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+/// Subtracts two numbers.
+///
+/// ```
+/// let difference: String = doctestFailure::sub(3, 2);
+/// ```
+pub fn sub(a: i32, b: i32) -> i32 {
+    a - b
+}
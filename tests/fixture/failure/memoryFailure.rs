@@ -0,0 +1,4 @@
+fn main() {
+    let huge = vec![0u8; 4 << 30];
+    println!("Allocated {} bytes", huge.len());
+}
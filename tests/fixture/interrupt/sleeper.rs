@@ -0,0 +1,9 @@
+// Tells the test it's running, then sleeps until it's killed
+use std::fs;
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    fs::write("sleeper.pid", std::process::id().to_string()).unwrap();
+    thread::sleep(Duration::from_secs(600));
+}
@@ -0,0 +1,4 @@
+fn main() {
+    let name = String::from("world");
+    println!("Hello {}!", name);
+}
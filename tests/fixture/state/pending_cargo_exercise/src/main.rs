@@ -0,0 +1,5 @@
+mod nested;
+
+fn main() {
+    nested::hello();
+}
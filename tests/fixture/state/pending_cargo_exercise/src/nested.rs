@@ -0,0 +1,5 @@
+// I AM NOT DONE
+
+pub fn hello() {
+    println!("Hello from another file");
+}
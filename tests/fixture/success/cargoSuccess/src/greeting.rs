@@ -0,0 +1,11 @@
+pub fn greet(name: &str) -> String {
+    format!("Hello, {name}!")
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn greets() {
+        assert_eq!(super::greet("unit"), "Hello, unit!");
+    }
+}
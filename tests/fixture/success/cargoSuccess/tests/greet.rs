@@ -0,0 +1,5 @@
+#[test]
+fn greets_from_outside() {
+    println!("THIS CRATE TOO SHALL PASS");
+    assert_eq!(cargo_success::greet("crate"), "Hello, crate!");
+}
@@ -0,0 +1,4 @@
+fn main() {
+    let values = [1, 2, 3];
+    println!("{} values", values.len());
+}
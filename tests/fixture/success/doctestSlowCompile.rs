@@ -0,0 +1,14 @@
+/// Compiling the example takes longer than the time limit, running it doesn't
+///
+/// ```
+/// #![allow(long_running_const_eval)]
+/// const SLOW: u64 = {
+///     let mut i = 0;
+///     while i < 1_500_000 {
+///         i += 1;
+///     }
+///     i
+/// };
+/// assert_eq!(SLOW, 1_500_000);
+/// ```
+pub fn nothing() {}
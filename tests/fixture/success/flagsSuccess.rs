@@ -0,0 +1,10 @@
+#[cfg(not(lesson))]
+compile_error!("this exercise needs `--cfg lesson`");
+
+fn main() {
+    // `dyn` is only a keyword since the 2018 edition
+    let dyn = 255u8;
+    // Wraps around to 0 with overflow checks off
+    let wrapped = dyn + std::hint::black_box(1);
+    println!("{}", wrapped);
+}
@@ -0,0 +1,7 @@
+// Leaves a process behind that keeps the output pipes open
+use std::process::Command;
+
+fn main() {
+    let _ = Command::new("sleep").arg("20").spawn();
+    println!("Left a sleeper behind");
+}
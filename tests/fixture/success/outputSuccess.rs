@@ -0,0 +1,4 @@
+fn main() {
+    println!("Hello world!");
+    println!("The answer is {}", 6 * 7);
+}
@@ -1,9 +1,59 @@
 use assert_cmd::prelude::*;
 use glob::glob;
 use predicates::boolean::PredicateBooleanExt;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::Read;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// A copy of a fixture in a temporary directory, removed when dropped,
+// so that the results rustlings caches and the progress it saves
+// neither stay in the fixture nor make later runs pass without checking
+struct Fixture(PathBuf);
+
+fn fixture(name: &str) -> Fixture {
+    static COPIES: AtomicUsize = AtomicUsize::new(0);
+    let dir = std::env::temp_dir().join(format!(
+        "rustlings_fixture_{name}_{}_{}",
+        std::process::id(),
+        COPIES.fetch_add(1, Ordering::Relaxed)
+    ));
+    copy_dir(&Path::new("tests/fixture").join(name), &dir);
+    Fixture(dir)
+}
+
+// Copy a directory without what building and running exercises leaves in it
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let name = entry.file_name();
+        if name == "target" || name == "Cargo.lock" || name == ".rustlings-state.json" {
+            continue;
+        }
+        if entry.file_type().unwrap().is_dir() {
+            copy_dir(&entry.path(), &to.join(&name));
+        } else {
+            fs::copy(entry.path(), to.join(&name)).unwrap();
+        }
+    }
+}
+
+impl Deref for Fixture {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
 
 #[test]
 fn runs_without_arguments() {
@@ -15,77 +65,571 @@ fn runs_without_arguments() {
 fn fails_when_in_wrong_dir() {
     Command::cargo_bin("rustlings")
         .unwrap()
-        .current_dir("tests/")
+        .current_dir(std::env::temp_dir())
         .assert()
         .code(1);
 }
 
+#[test]
+fn finds_workspace_from_subdirectory() {
+    let success = fixture("success");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "compSuccess"])
+        .current_dir(success.join("cargoSuccess/src"))
+        .assert()
+        .success();
+}
+
+#[test]
+fn workspace_from_env_and_option() {
+    let success = fixture("success");
+    let failure = fixture("failure");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "compSuccess"])
+        .env("RUSTLINGS_HOME", &*success)
+        .current_dir(std::env::temp_dir())
+        .assert()
+        .success();
+    // The option wins over the environment
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .arg("--workspace")
+        .arg(&*failure)
+        .args(["run", "compFailure"])
+        .env("RUSTLINGS_HOME", &*success)
+        .assert()
+        .code(2);
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--workspace", "tests/fixture", "list"])
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("has no info.toml"));
+}
+
 #[test]
 fn verify_all_success() {
+    let success = fixture("success");
     Command::cargo_bin("rustlings")
         .unwrap()
         .arg("verify")
-        .current_dir("tests/fixture/success")
+        .current_dir(&*success)
         .assert()
         .success();
 }
 
 #[test]
 fn verify_fails_if_some_fails() {
+    let failure = fixture("failure");
     Command::cargo_bin("rustlings")
         .unwrap()
         .arg("verify")
-        .current_dir("tests/fixture/failure")
+        .current_dir(&*failure)
         .assert()
-        .code(1);
+        .code(2);
+}
+
+#[test]
+fn verify_all_success_in_parallel() {
+    let success = fixture("success");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["verify", "--jobs", "2"])
+        .current_dir(&*success)
+        .assert()
+        .success();
+}
+
+#[test]
+fn verify_fails_in_parallel_if_some_fails() {
+    let failure = fixture("failure");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["verify", "--jobs", "2"])
+        .current_dir(&*failure)
+        .assert()
+        .code(2)
+        .stdout(predicates::str::contains("compFailure.rs"));
+}
+
+#[test]
+fn cache_clean() {
+    let success = fixture("success");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["cache", "clean"])
+        .current_dir(&*success)
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Cleaned the cache"));
+}
+
+#[test]
+fn verify_stops_at_pending_marker() {
+    let state = fixture("state");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--format", "json", "verify"])
+        .current_dir(&*state)
+        .assert()
+        .code(4)
+        .stdout(predicates::str::contains(
+            r#"{"event":"pending_marker","exercise":"pending_exercise","line":"#,
+        ));
+}
+
+#[test]
+fn verify_junit_report_keeps_going() {
+    let state = fixture("state");
+    let report = std::env::temp_dir().join(format!("rustlings_junit_{}.xml", std::process::id()));
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["verify", "--keep-going", "--junit"])
+        .arg(&report)
+        .current_dir(&*state)
+        .assert()
+        .code(4);
+
+    let xml = std::fs::read_to_string(&report).unwrap();
+    std::fs::remove_file(&report).unwrap();
+    assert!(xml
+        .contains(r#"<testsuite name="exercises" tests="4" failures="0" errors="0" skipped="3""#));
+    assert!(xml.contains(
+        r#"<testcase name="finished_exercise" classname="exercises" file="finished_exercise.rs""#
+    ));
+}
+
+#[test]
+fn run_single_compile_failure_json() {
+    let failure = fixture("failure");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--format", "json", "run", "compFailure"])
+        .current_dir(&*failure)
+        .assert()
+        .code(2)
+        .stdout(
+            predicates::str::starts_with(r#"{"event":"started","exercise":"compFailure""#)
+                .and(predicates::str::contains(r#""event":"compile_failed""#))
+                .and(predicates::str::contains(r#""errors":1"#)),
+        );
+}
+
+#[test]
+fn verify_json_keeps_warnings_off_stdout() {
+    let dir = std::env::temp_dir().join(format!("rustlings_json_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("info.toml"),
+        "[[exercises]]\nname = \"done\"\npath = \"done.rs\"\nmode = \"compile\"\nhint = \"\"\n",
+    )
+    .unwrap();
+    fs::write(dir.join("done.rs"), "fn main() {}\n").unwrap();
+    // The progress can't be saved over a directory
+    fs::create_dir_all(dir.join(".rustlings-state.json")).unwrap();
+    let assert = Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--format", "json", "verify"])
+        .current_dir(&dir)
+        .assert();
+    let output = assert.get_output().clone();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().all(|line| line.starts_with('{')));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Failed to save your progress"));
 }
 
 #[test]
 fn run_single_compile_success() {
+    let success = fixture("success");
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["run", "compSuccess"])
-        .current_dir("tests/fixture/success/")
+        .args(["run", "compSuccess"])
+        .current_dir(&*success)
         .assert()
         .success();
 }
 
 #[test]
 fn run_single_compile_failure() {
+    let failure = fixture("failure");
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["run", "compFailure"])
-        .current_dir("tests/fixture/failure/")
+        .args(["run", "compFailure"])
+        .current_dir(&*failure)
         .assert()
-        .code(1);
+        .code(2);
+}
+
+#[test]
+fn run_single_compile_timeout() {
+    let failure = fixture("failure");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "timeoutFailure"])
+        .current_dir(&*failure)
+        .assert()
+        .code(5)
+        .stdout(
+            predicates::str::contains("Started looping")
+                .and(predicates::str::contains("time limit of 1s")),
+        );
+}
+
+#[test]
+#[cfg(unix)]
+fn run_single_compile_leaves_no_process_behind() {
+    let success = fixture("success");
+    let started = std::time::Instant::now();
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "forkSuccess"])
+        .current_dir(&*success)
+        .assert()
+        .code(0)
+        .stdout(predicates::str::contains("Left a sleeper behind"));
+    // The sleeper is killed with the binary instead of being waited for
+    assert!(started.elapsed() < std::time::Duration::from_secs(15));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn run_single_compile_memory_limit() {
+    let failure = fixture("failure");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "memoryFailure"])
+        .current_dir(&*failure)
+        .assert()
+        .code(5)
+        .stdout(predicates::str::contains("memory limit"));
+}
+
+#[test]
+fn run_single_compile_output_limit() {
+    let failure = fixture("failure");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "outputFailure"])
+        .current_dir(&*failure)
+        .assert()
+        .code(5)
+        .stdout(predicates::str::contains("output limit of 1 MiB"));
+}
+
+#[test]
+fn run_single_expected_output() {
+    let success = fixture("success");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "outputSuccess"])
+        .current_dir(&*success)
+        .assert()
+        .success();
+}
+
+#[test]
+fn run_single_output_mismatch() {
+    let failure = fixture("failure");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "outputMismatch"])
+        .current_dir(&*failure)
+        .assert()
+        .code(6)
+        .stdout(
+            predicates::str::contains("- See you later!")
+                .and(predicates::str::contains("+ Goodbye world!")),
+        );
+}
+
+#[test]
+fn run_single_stdin_cases() {
+    let success = fixture("success");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "stdinSuccess"])
+        .current_dir(&*success)
+        .assert()
+        .success()
+        .stdout(
+            predicates::str::contains("with input two numbers")
+                .and(predicates::str::contains("with input #2"))
+                .and(predicates::str::contains("with input empty")),
+        );
+}
+
+#[test]
+fn run_single_stdin_case_failure() {
+    let failure = fixture("failure");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "stdinFailure"])
+        .current_dir(&*failure)
+        .assert()
+        .code(3)
+        .stdout(
+            predicates::str::contains("with input numbers")
+                .and(predicates::str::contains("With input not a number"))
+                .and(predicates::str::contains("ParseIntError")),
+        );
+}
+
+#[test]
+fn run_single_compile_fail_success() {
+    let success = fixture("success");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "compileFailSuccess"])
+        .current_dir(&*success)
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "failed to compile with the expected errors",
+        ));
+}
+
+#[test]
+fn run_single_compile_fail_compiles() {
+    let failure = fixture("failure");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "compileFailCompiles"])
+        .current_dir(&*failure)
+        .assert()
+        .code(7)
+        .stdout(predicates::str::contains(
+            "compiled, but it should fail to compile with E0382",
+        ));
+}
+
+#[test]
+fn run_single_compile_fail_wrong_error() {
+    let failure = fixture("failure");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "compileFailWrongError"])
+        .current_dir(&*failure)
+        .assert()
+        .code(7)
+        .stdout(predicates::str::contains("with E0308 instead of E0382"));
+}
+
+#[test]
+fn run_single_doctest_success() {
+    let success = fixture("success");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "doctestSuccess"])
+        .current_dir(&*success)
+        .assert()
+        .success();
+}
+
+#[test]
+fn run_single_doctest_compiling_longer_than_its_time_limit() {
+    let success = fixture("success");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "doctestSlowCompile"])
+        .current_dir(&*success)
+        .assert()
+        .success();
+}
+
+#[test]
+fn run_single_doctest_failure() {
+    let failure = fixture("failure");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "doctestFailure"])
+        .current_dir(&*failure)
+        .assert()
+        .code(3)
+        .stdout(
+            predicates::str::contains("doctestFailure.rs - add (line 3) ... FAILED").and(
+                predicates::str::contains("doctestFailure.rs - sub (line 12) ... FAILED"),
+            ),
+        );
+}
+
+#[test]
+fn run_single_clippy_success() {
+    let success = fixture("success");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "clippySuccess"])
+        .current_dir(&*success)
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("3 values"));
+}
+
+#[test]
+fn run_single_clippy_failure() {
+    let failure = fixture("failure");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "clippyFailure"])
+        .current_dir(&*failure)
+        .assert()
+        .code(2)
+        .stdout(
+            predicates::str::contains("strict comparison of `f32` or `f64`").and(
+                predicates::str::contains("deny warnings, clippy::float_cmp"),
+            ),
+        );
+}
+
+#[test]
+fn run_leaves_no_build_artifacts_behind() {
+    let success = fixture("success");
+    let mut rustlings = Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "compSuccess"])
+        .current_dir(&*success)
+        .stdout(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+    let session = success.join(format!("target/rustlings/session-{}", rustlings.id()));
+    assert!(rustlings.wait().unwrap().success());
+    assert!(!session.exists());
+}
+
+#[test]
+fn run_sweeps_stale_sessions() {
+    let success = fixture("success");
+    // No process has the largest pid, it's above the kernel's limit
+    let stale = success.join(format!("target/rustlings/session-{}", i32::MAX));
+    fs::create_dir_all(&stale).unwrap();
+    File::create(stale.join("temp_ThreadId1")).unwrap();
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "compSuccess"])
+        .current_dir(&*success)
+        .assert()
+        .success();
+    assert_eq!(stale.exists(), !cfg!(unix));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn interrupted_run_kills_exercise_and_cleans_up() {
+    use std::time::{Duration, Instant};
+    let dir = fixture("interrupt");
+    let pid_file = dir.join("sleeper.pid");
+    let mut rustlings = Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "sleeper"])
+        .current_dir(&*dir)
+        .stdout(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+    let session = dir.join(format!("target/rustlings/session-{}", rustlings.id()));
+
+    let started = Instant::now();
+    let sleeper = loop {
+        match fs::read_to_string(&pid_file) {
+            Ok(pid) if !pid.is_empty() => break pid,
+            _ if started.elapsed() > Duration::from_secs(60) => {
+                let _ = rustlings.kill();
+                panic!("The exercise didn't start");
+            }
+            _ => std::thread::sleep(Duration::from_millis(50)),
+        }
+    };
+    let _ = fs::remove_file(&pid_file);
+    Command::new("kill")
+        .args(["-INT", &rustlings.id().to_string()])
+        .assert()
+        .success();
+    let status = rustlings.wait().unwrap();
+
+    // Killed processes nobody reaped are zombies, which don't run anymore
+    let running = |pid: &str| {
+        fs::read_to_string(format!("/proc/{pid}/stat"))
+            .is_ok_and(|stat| stat.split_whitespace().nth(2) != Some("Z"))
+    };
+    let started = Instant::now();
+    while running(&sleeper) && started.elapsed() < Duration::from_secs(5) {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    assert!(!status.success());
+    assert!(!running(&sleeper));
+    assert!(!session.exists());
+}
+
+#[test]
+fn run_single_with_edition_cfg_and_rustc_args() {
+    let success = fixture("success");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "flagsSuccess"])
+        .current_dir(&*success)
+        .assert()
+        .success();
 }
 
 #[test]
 fn run_single_test_success() {
+    let success = fixture("success");
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["run", "testSuccess"])
-        .current_dir("tests/fixture/success/")
+        .args(["run", "testSuccess"])
+        .current_dir(&*success)
         .assert()
         .success();
 }
 
+#[test]
+fn run_single_cargo_success() {
+    let success = fixture("success");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--nocapture", "run", "cargoSuccess"])
+        .current_dir(&*success)
+        .assert()
+        .success()
+        .stdout(
+            predicates::str::contains("THIS CRATE TOO SHALL PASS")
+                .and(predicates::str::contains("greeting::tests::greets ... ok")),
+        );
+}
+
+#[test]
+fn run_single_cargo_failure() {
+    let failure = fixture("failure");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "cargoFailure"])
+        .current_dir(&*failure)
+        .assert()
+        .code(3)
+        .stdout(predicates::str::contains("test answers ... FAILED"));
+}
+
 #[test]
 fn run_single_test_failure() {
+    let failure = fixture("failure");
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["run", "testFailure"])
-        .current_dir("tests/fixture/failure/")
+        .args(["run", "testFailure"])
+        .current_dir(&*failure)
         .assert()
-        .code(1);
+        .code(2);
 }
 
 #[test]
 fn run_single_test_not_passed() {
+    let failure = fixture("failure");
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["run", "testNotPassed.rs"])
-        .current_dir("tests/fixture/failure/")
+        .args(["run", "testNotPassed.rs"])
+        .current_dir(&*failure)
         .assert()
         .code(1);
 }
@@ -102,10 +646,11 @@ fn run_single_test_no_filename() {
 
 #[test]
 fn run_single_test_no_exercise() {
+    let failure = fixture("failure");
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["run", "compNoExercise.rs"])
-        .current_dir("tests/fixture/failure")
+        .args(["run", "compNoExercise.rs"])
+        .current_dir(&*failure)
         .assert()
         .code(1);
 }
@@ -114,7 +659,7 @@ fn run_single_test_no_exercise() {
 fn reset_single_exercise() {
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["reset", "intro1"])
+        .args(["reset", "intro1"])
         .assert()
         .code(0);
 }
@@ -126,22 +671,364 @@ fn reset_no_exercise() {
         .arg("reset")
         .assert()
         .code(1)
-        .stderr(predicates::str::contains(
-            "positional arguments not provided",
+        .stdout(predicates::str::contains(
+            "Give either the name of an exercise, --section or --all",
         ));
 }
 
+// A workspace outside of any git checkout with both intro exercises edited
+fn edited_intro_workspace(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("rustlings_{name}_{}", std::process::id()));
+    fs::create_dir_all(dir.join("exercises/intro")).unwrap();
+    fs::write(
+        dir.join("info.toml"),
+        r#"
+[[exercises]]
+name = "intro1"
+path = "exercises/intro/intro1.rs"
+mode = "compile"
+hint = ""
+
+[[exercises]]
+name = "intro2"
+path = "exercises/intro/intro2.rs"
+mode = "compile"
+hint = ""
+"#,
+    )
+    .unwrap();
+    for exercise in ["intro1", "intro2"] {
+        let path = format!("exercises/intro/{exercise}.rs");
+        fs::write(dir.join(path), "// my attempt\n").unwrap();
+    }
+    dir
+}
+
+#[test]
+fn reset_restores_original_and_backs_up() {
+    let dir = edited_intro_workspace("reset");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["reset", "intro1", "--yes"])
+        .current_dir(&dir)
+        .assert()
+        .code(0)
+        .stdout(predicates::str::contains("Your versions are backed up in"));
+
+    let intro1 = fs::read_to_string(dir.join("exercises/intro/intro1.rs")).unwrap();
+    let intro2 = fs::read_to_string(dir.join("exercises/intro/intro2.rs")).unwrap();
+    let backups: Vec<_> = glob(&format!(
+        "{}/.rustlings/backups/*/exercises/intro/intro1.rs",
+        dir.display()
+    ))
+    .unwrap()
+    .collect();
+    let backup = fs::read_to_string(backups[0].as_ref().unwrap()).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(
+        intro1,
+        fs::read_to_string("exercises/intro/intro1.rs").unwrap()
+    );
+    assert_eq!(intro2, "// my attempt\n");
+    assert_eq!(backups.len(), 1);
+    assert_eq!(backup, "// my attempt\n");
+}
+
+#[test]
+fn reset_asks_for_confirmation() {
+    let dir = edited_intro_workspace("reset_declined");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["reset", "--section", "intro"])
+        .current_dir(&dir)
+        .with_stdin()
+        .buffer("n\n")
+        .assert()
+        .code(0)
+        .stdout(predicates::str::contains("exercises/intro/intro2.rs"))
+        .stdout(predicates::str::contains("Nothing was reset."));
+    let declined = fs::read_to_string(dir.join("exercises/intro/intro1.rs")).unwrap();
+
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["reset", "--section", "intro"])
+        .current_dir(&dir)
+        .with_stdin()
+        .buffer("y\n")
+        .assert()
+        .code(0)
+        .stdout(predicates::str::contains("Reset 2 file(s)"));
+    let intro2 = fs::read_to_string(dir.join("exercises/intro/intro2.rs")).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(declined, "// my attempt\n");
+    assert_eq!(
+        intro2,
+        fs::read_to_string("exercises/intro/intro2.rs").unwrap()
+    );
+}
+
+#[test]
+fn diff_against_original() {
+    let dir = edited_intro_workspace("diff");
+    let original = fs::read_to_string("exercises/intro/intro2.rs").unwrap();
+    fs::write(
+        dir.join("exercises/intro/intro2.rs"),
+        format!("// a note\n{}", original.replace("    ", "\t")),
+    )
+    .unwrap();
+
+    let diff = Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["diff", "--all"])
+        .current_dir(&dir)
+        .assert();
+    let ignoring_trivial = Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["diff", "intro2", "--ignore-trivial"])
+        .current_dir(&dir)
+        .assert();
+    fs::remove_dir_all(&dir).unwrap();
+
+    diff.code(0)
+        .stdout(predicates::str::contains("+ // my attempt"))
+        .stdout(predicates::str::contains("@@ -1,"))
+        .stdout(predicates::str::contains("+ // a note"));
+    ignoring_trivial
+        .code(0)
+        .stdout("intro2 is the same as the original.\n");
+}
+
+#[test]
+fn init_creates_a_workspace() {
+    let dir = std::env::temp_dir().join(format!("rustlings_init_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["init", "--git"])
+        .arg(&dir)
+        .assert()
+        .code(0);
+    let info = fs::read_to_string(dir.join("info.toml")).unwrap();
+    let intro1 = fs::read_to_string(dir.join("exercises/intro/intro1.rs")).unwrap();
+    let has_solutions = dir.join("solutions/intro/intro1.rs").is_file();
+    let workspace_works = Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "intro1"])
+        .current_dir(&dir)
+        .assert();
+    let solution = Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["solution", "intro1"])
+        .current_dir(&dir)
+        .assert();
+    let has_git = dir.join(".git").is_dir();
+    let has_gitignore = dir.join(".gitignore").is_file();
+    let second_init = Command::cargo_bin("rustlings")
+        .unwrap()
+        .arg("init")
+        .arg(&dir)
+        .assert();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(info, fs::read_to_string("info.toml").unwrap());
+    assert_eq!(
+        intro1,
+        fs::read_to_string("exercises/intro/intro1.rs").unwrap()
+    );
+    workspace_works.code(0);
+    assert!(!has_solutions);
+    solution
+        .code(0)
+        .stdout(predicates::str::contains("fn main()"));
+    assert!(has_git);
+    assert!(has_gitignore);
+    second_init
+        .code(1)
+        .stdout(predicates::str::contains("use --force to overwrite it"));
+}
+
+#[test]
+fn install_and_use_packs() {
+    let dir = std::env::temp_dir().join(format!("rustlings_packs_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("info.toml"), "exercises = []\n").unwrap();
+    let rustlings = |args: &[&str]| {
+        let mut command = Command::cargo_bin("rustlings").unwrap();
+        command.args(args).current_dir(&dir);
+        command
+    };
+    let source = fs::canonicalize("tests/fixture/pack").unwrap();
+    let tarball = dir.join("greetings.tar");
+    Command::new("tar")
+        .arg("-cf")
+        .arg(&tarball)
+        .arg("-C")
+        .arg(source.parent().unwrap())
+        .arg("pack")
+        .assert()
+        .success();
+
+    let added = rustlings(&["pack", "add"]).arg(&source).assert();
+    let added_again = rustlings(&["pack", "add"]).arg(&tarball).assert();
+    let run = rustlings(&["--pack", "greetings", "run", "hello"]).assert();
+    let hello = dir.join("packs/greetings/exercises/hello.rs");
+    fs::write(&hello, "// my attempt\n").unwrap();
+    let reset = rustlings(&["--pack", "greetings", "reset", "hello", "--yes"]).assert();
+    let reset_hello = fs::read_to_string(&hello).unwrap();
+    let unknown = rustlings(&["--pack", "farewells", "list"]).assert();
+    let selected = rustlings(&["pack", "use", "greetings"]).assert();
+    let list = rustlings(&["list"]).assert();
+    let packs = rustlings(&["pack", "list"]).assert();
+    let progress_in_pack = dir.join("packs/greetings/.rustlings-state.json").exists();
+    let progress_in_root = dir.join(".rustlings-state.json").exists();
+    fs::remove_dir_all(&dir).unwrap();
+
+    added
+        .code(0)
+        .stdout(predicates::str::contains("Installed greetings v1.0.0"));
+    added_again
+        .code(1)
+        .stdout(predicates::str::contains("already installed"));
+    run.code(0)
+        .stdout(predicates::str::contains("Hello from a pack!"));
+    reset.code(0);
+    assert_eq!(
+        reset_hello,
+        fs::read_to_string(source.join("exercises/hello.rs")).unwrap()
+    );
+    unknown.code(1).stdout(predicates::str::contains(
+        "There is no pack named 'farewells'",
+    ));
+    selected.code(0);
+    list.code(0)
+        .stdout(predicates::str::is_match(r"hello\s.*Done\s+greetings").unwrap());
+    packs
+        .code(0)
+        .stdout(predicates::str::contains("rustlings"))
+        .stdout(predicates::str::contains("* greetings"));
+    assert!(progress_in_pack);
+    assert!(!progress_in_root);
+}
+
+#[test]
+fn dev_check_finds_problems() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["dev", "check"])
+        .current_dir("tests/fixture/dev")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::starts_with(
+            "info.toml:3: good is the same as its solution, which should be finished
+info.toml:8: The name good is already used by the exercise at line 2
+info.toml:9: The file exercises/topic/missing.rs of good doesn't exist
+info.toml:11: good has an empty hint
+info.toml:15: done has no `I AM NOT DONE` marker
+info.toml:22: fails is a compile_fail exercise with no expected_errors, it can't pass
+exercises/topic has no README.md
+exercises/topic/unused.rs isn't used by any exercise in info.toml
+",
+        ));
+}
+
+#[test]
+fn dev_check_invalid_info_toml() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["dev", "check"])
+        .current_dir("tests/fixture/dev_invalid")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::starts_with(
+            "info.toml:4: typo: unknown variant `compiled`",
+        ));
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .arg("list")
+        .current_dir("tests/fixture/dev_invalid")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("rustlings dev check"));
+}
+
 #[test]
 fn get_hint_for_single_test() {
+    let failure = fixture("failure");
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["hint", "testFailure"])
-        .current_dir("tests/fixture/failure")
+        .args(["hint", "testFailure"])
+        .current_dir(&*failure)
         .assert()
         .code(0)
         .stdout("Hello!\n");
 }
 
+#[test]
+fn get_hint_levels() {
+    let failure = fixture("failure");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["hint", "compFailure", "--level", "2"])
+        .current_dir(&*failure)
+        .assert()
+        .code(0)
+        .stdout("Hint 2 of 3:\nThe compiler points at the line to fix.\n");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["hint", "compFailure", "--all"])
+        .current_dir(&*failure)
+        .assert()
+        .code(0)
+        .stdout(
+            predicates::str::contains("Hint 1 of 3:\nLook at the error message.\n\n").and(
+                predicates::str::contains("Hint 3 of 3:\nRemove the line.\n"),
+            ),
+        );
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["hint", "compFailure", "--level", "4"])
+        .current_dir(&*failure)
+        .assert()
+        .code(1);
+}
+
+#[test]
+fn solution_once_done() {
+    let solution = fixture("solution");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["solution", "greeting"])
+        .current_dir(&*solution)
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("shown once you have done it"));
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "greeting"])
+        .current_dir(&*solution)
+        .assert()
+        .success();
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["solution", "greeting"])
+        .current_dir(&*solution)
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("let name = \"world\";"));
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["solution", "greeting", "--diff"])
+        .current_dir(&*solution)
+        .assert()
+        .success()
+        .stdout(
+            predicates::str::contains("-     let name = String::from(\"world\");")
+                .and(predicates::str::contains("+     let name = \"world\";")),
+        );
+}
+
 #[test]
 fn all_exercises_require_confirmation() {
     for exercise in glob("exercises/**/*.rs").unwrap() {
@@ -169,10 +1056,11 @@ fn all_exercises_require_confirmation() {
 
 #[test]
 fn run_compile_exercise_does_not_prompt() {
+    let state = fixture("state");
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["run", "pending_exercise"])
-        .current_dir("tests/fixture/state")
+        .args(["run", "pending_exercise"])
+        .current_dir(&*state)
         .assert()
         .code(0)
         .stdout(predicates::str::contains("I AM NOT DONE").not());
@@ -180,10 +1068,11 @@ fn run_compile_exercise_does_not_prompt() {
 
 #[test]
 fn run_test_exercise_does_not_prompt() {
+    let state = fixture("state");
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["run", "pending_test_exercise"])
-        .current_dir("tests/fixture/state")
+        .args(["run", "pending_test_exercise"])
+        .current_dir(&*state)
         .assert()
         .code(0)
         .stdout(predicates::str::contains("I AM NOT DONE").not());
@@ -191,10 +1080,11 @@ fn run_test_exercise_does_not_prompt() {
 
 #[test]
 fn run_single_test_success_with_output() {
+    let success = fixture("success");
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["--nocapture", "run", "testSuccess"])
-        .current_dir("tests/fixture/success/")
+        .args(["--nocapture", "run", "testSuccess"])
+        .current_dir(&*success)
         .assert()
         .code(0)
         .stdout(predicates::str::contains("THIS TEST TOO SHALL PASS"));
@@ -202,10 +1092,11 @@ fn run_single_test_success_with_output() {
 
 #[test]
 fn run_single_test_success_without_output() {
+    let success = fixture("success");
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["run", "testSuccess"])
-        .current_dir("tests/fixture/success/")
+        .args(["run", "testSuccess"])
+        .current_dir(&*success)
         .assert()
         .code(0)
         .stdout(predicates::str::contains("THIS TEST TOO SHALL PASS").not());
@@ -213,20 +1104,28 @@ fn run_single_test_success_without_output() {
 
 #[test]
 fn run_rustlings_list() {
+    let success = fixture("success");
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["list"])
-        .current_dir("tests/fixture/success")
+        .args(["list"])
+        .current_dir(&*success)
         .assert()
         .success();
 }
 
 #[test]
 fn run_rustlings_list_no_pending() {
+    let success = fixture("success");
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["list"])
-        .current_dir("tests/fixture/success")
+        .arg("verify")
+        .current_dir(&*success)
+        .assert()
+        .success();
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["list"])
+        .current_dir(&*success)
         .assert()
         .success()
         .stdout(predicates::str::contains("Pending").not());
@@ -234,10 +1133,17 @@ fn run_rustlings_list_no_pending() {
 
 #[test]
 fn run_rustlings_list_both_done_and_pending() {
+    let state = fixture("state");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "finished_exercise"])
+        .current_dir(&*state)
+        .assert()
+        .success();
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["list"])
-        .current_dir("tests/fixture/state")
+        .args(["list"])
+        .current_dir(&*state)
         .assert()
         .success()
         .stdout(predicates::str::contains("Done").and(predicates::str::contains("Pending")));
@@ -245,10 +1151,11 @@ fn run_rustlings_list_both_done_and_pending() {
 
 #[test]
 fn run_rustlings_list_without_pending() {
+    let state = fixture("state");
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["list", "--solved"])
-        .current_dir("tests/fixture/state")
+        .args(["list", "--solved"])
+        .current_dir(&*state)
         .assert()
         .success()
         .stdout(predicates::str::contains("Pending").not());
@@ -256,11 +1163,26 @@ fn run_rustlings_list_without_pending() {
 
 #[test]
 fn run_rustlings_list_without_done() {
+    let state = fixture("state");
     Command::cargo_bin("rustlings")
         .unwrap()
-        .args(&["list", "--unsolved"])
-        .current_dir("tests/fixture/state")
+        .args(["list", "--unsolved"])
+        .current_dir(&*state)
         .assert()
         .success()
         .stdout(predicates::str::contains("Done").not());
 }
+
+#[test]
+fn run_rustlings_list_json() {
+    let state = fixture("state");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--format", "json", "list", "--unsolved"])
+        .current_dir(&*state)
+        .assert()
+        .success()
+        .stdout(predicates::str::starts_with(
+            r#"[{"name":"pending_exercise","path":"pending_exercise.rs","mode":"compile","status":"pending","pack":"rustlings"}"#,
+        ));
+}